@@ -0,0 +1,47 @@
+use std::error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+pub use reader::{BitReader, Bytes, Hex, Source, Stream};
+pub use writer::BitWriter;
+
+mod reader;
+mod writer;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    // The stream ended at position, which is a bit offset, before all the bits wanted were read.
+    UnexpectedEnd { position: u64, wanted: u32 },
+    // Hex text had something other than a hex digit at offset, which is a character offset.
+    InvalidHex { offset: u64, found: char },
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnexpectedEnd { position, wanted } => {
+                write!(f, "bit {}: wanted {} more bits, found end of stream", position, wanted)
+            }
+            Error::InvalidHex { offset, found } => write!(f, "character {}: expected hex digit, found {:?}", offset, found),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
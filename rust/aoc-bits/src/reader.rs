@@ -0,0 +1,199 @@
+use std::io;
+use std::io::BufRead;
+
+use crate::{Error, Result};
+
+// A Source supplies the bits of a stream a few at a time, most significant bit first.
+pub trait Source {
+    // next returns the next chunk of up to 8 bits, as (bits, number of bits), or None at the end
+    // of the stream.
+    fn next(&mut self) -> Result<Option<(u8, u32)>>;
+}
+
+// Hex reads hex text, four bits per digit. Trailing whitespace, such as the line ending of a
+// puzzle input, is not part of the stream.
+pub struct Hex<'a> {
+    text: &'a [u8],
+    offset: usize,
+}
+
+impl Source for Hex<'_> {
+    fn next(&mut self) -> Result<Option<(u8, u32)>> {
+        let Some(&c) = self.text.get(self.offset) else { return Ok(None) };
+        let digit = (c as char).to_digit(16).ok_or_else(|| Error::InvalidHex {
+            offset: self.offset as u64,
+            found: String::from_utf8_lossy(&self.text[self.offset..]).chars().next().unwrap(),
+        })?;
+        self.offset += 1;
+        Ok(Some((digit as u8, 4)))
+    }
+}
+
+// Bytes reads raw bytes from memory.
+pub struct Bytes<'a> {
+    bytes: &'a [u8],
+}
+
+impl Source for Bytes<'_> {
+    fn next(&mut self) -> Result<Option<(u8, u32)>> {
+        let Some((&byte, rest)) = self.bytes.split_first() else { return Ok(None) };
+        self.bytes = rest;
+        Ok(Some((byte, 8)))
+    }
+}
+
+// Stream reads raw bytes from a reader. It reads a byte at a time, so the reader must be buffered.
+pub struct Stream<R> {
+    bytes: io::Bytes<R>,
+}
+
+impl<R: BufRead> Source for Stream<R> {
+    fn next(&mut self) -> Result<Option<(u8, u32)>> {
+        Ok(self.bytes.next().transpose()?.map(|byte| (byte, 8)))
+    }
+}
+
+// BitReader reads unsigned integers of up to 64 bits from a stream of bits, most significant bit
+// first, keeping track of its position in bits from the start of the stream.
+pub struct BitReader<S> {
+    source: S,
+    // The next bits of the stream are the low `buffered` bits of buffer.
+    buffer: u128,
+    buffered: u32,
+    position: u64,
+}
+
+impl<'a> BitReader<Hex<'a>> {
+    pub fn hex(text: &'a [u8]) -> Self {
+        BitReader::from_source(Hex { text: text.trim_ascii_end(), offset: 0 })
+    }
+}
+
+impl<'a> BitReader<Bytes<'a>> {
+    pub fn bytes(bytes: &'a [u8]) -> Self {
+        BitReader::from_source(Bytes { bytes })
+    }
+}
+
+impl<R: BufRead> BitReader<Stream<R>> {
+    pub fn new(reader: R) -> Self {
+        BitReader::from_source(Stream { bytes: reader.bytes() })
+    }
+}
+
+impl<S: Source> BitReader<S> {
+    pub fn from_source(source: S) -> Self {
+        BitReader { source, buffer: 0, buffered: 0, position: 0 }
+    }
+
+    // position is the number of bits read or skipped so far.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    // read reads the next n bits, where n is at most 64.
+    pub fn read(&mut self, n: u32) -> Result<u64> {
+        let value = self.peek(n)?;
+        self.buffered -= n;
+        self.position += n as u64;
+        Ok(value)
+    }
+
+    // peek returns the next n bits, where n is at most 64, without moving past them.
+    pub fn peek(&mut self, n: u32) -> Result<u64> {
+        assert!(n <= 64, "cannot read {} bits at once", n);
+        self.fill(n)?;
+        if self.buffered < n {
+            return Err(Error::UnexpectedEnd { position: self.position, wanted: n });
+        }
+        Ok((self.buffer >> (self.buffered - n)) as u64 & mask(n))
+    }
+
+    // skip moves past the next n bits.
+    pub fn skip(&mut self, mut n: u64) -> Result<()> {
+        while n > 0 {
+            let step = n.min(64) as u32;
+            self.read(step)?;
+            n -= step as u64;
+        }
+        Ok(())
+    }
+
+    // at_end returns whether every bit has been read.
+    pub fn at_end(&mut self) -> Result<bool> {
+        self.fill(1)?;
+        Ok(self.buffered == 0)
+    }
+
+    // fill buffers at least n bits, unless the stream ends first.
+    fn fill(&mut self, n: u32) -> Result<()> {
+        while self.buffered < n {
+            let Some((bits, width)) = self.source.next()? else { break };
+            self.buffer = (self.buffer << width | bits as u128) & ((1 << (self.buffered + width)) - 1);
+            self.buffered += width;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn mask(n: u32) -> u64 {
+    if n == 64 { u64::MAX } else { (1 << n) - 1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    #[test]
+    fn test_hex() {
+        let mut reader = BitReader::hex(b"D2FE28\n");
+        assert_eq!(reader.read(3).unwrap(), 6);
+        assert_eq!(reader.read(3).unwrap(), 4);
+        assert_eq!(reader.read(3).unwrap(), 5);
+        assert_eq!(reader.read(8).unwrap(), 252);
+        assert_eq!(reader.read(4).unwrap(), 5);
+        assert_eq!(reader.position(), 21);
+        assert_eq!(reader.read(2).unwrap(), 0);
+        assert_eq!(reader.read(1).unwrap(), 0);
+        assert!(reader.at_end().unwrap());
+        assert!(matches!(reader.read(1), Err(Error::UnexpectedEnd { position: 24, wanted: 1 })));
+
+        let mut reader = BitReader::hex(b"D2FE28");
+        assert_eq!(reader.read(20).unwrap(), 864226);
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        let mut reader = BitReader::hex(b"D2xE28");
+        assert_eq!(reader.read(8).unwrap(), 0xD2);
+        assert_eq!(reader.read(4).unwrap_err().to_string(), "character 2: expected hex digit, found 'x'");
+    }
+
+    #[test]
+    fn test_wide_reads() {
+        let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xF0];
+        let mut reader = BitReader::bytes(&bytes);
+        assert_eq!(reader.read(4).unwrap(), 0);
+        assert_eq!(reader.read(64).unwrap(), 0x123456789ABCDEFF);
+        assert_eq!(reader.read(4).unwrap(), 0);
+        assert!(reader.at_end().unwrap());
+
+        let mut reader = BitReader::bytes(&bytes);
+        assert_eq!(reader.read(64).unwrap(), 0x0123456789ABCDEF);
+    }
+
+    #[test]
+    fn test_peek_skip() {
+        let mut reader = BitReader::new(BufReader::new(&[0b1010_1100u8, 0b0011_0101][..]));
+        assert_eq!(reader.peek(4).unwrap(), 0b1010);
+        assert_eq!(reader.position(), 0);
+        reader.skip(6).unwrap();
+        assert_eq!(reader.position(), 6);
+        assert_eq!(reader.read(6).unwrap(), 0b00_0011);
+        assert!(matches!(reader.peek(5), Err(Error::UnexpectedEnd { position: 12, wanted: 5 })));
+        assert_eq!(reader.read(4).unwrap(), 0b0101);
+        assert!(matches!(reader.skip(1), Err(Error::UnexpectedEnd { position: 16, wanted: 1 })));
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Write;
+
+use crate::reader::mask;
+
+// BitWriter writes unsigned integers of up to 64 bits as a stream of bits, most significant bit
+// first, in the order a BitReader reads them back.
+#[derive(Debug, Default, Clone)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    // The bits that do not make a whole byte yet are the low `pending` bits of buffer.
+    buffer: u128,
+    pending: u32,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        BitWriter::default()
+    }
+
+    // position is the number of bits written so far.
+    pub fn position(&self) -> u64 {
+        self.bytes.len() as u64 * 8 + self.pending as u64
+    }
+
+    // write writes value as n bits, where n is at most 64 and value fits in n bits.
+    pub fn write(&mut self, value: u64, n: u32) {
+        assert!(n <= 64, "cannot write {} bits at once", n);
+        assert!(value & !mask(n) == 0, "{} does not fit in {} bits", value, n);
+        self.buffer = self.buffer << n | value as u128;
+        self.pending += n;
+        while self.pending >= 8 {
+            self.pending -= 8;
+            self.bytes.push((self.buffer >> self.pending) as u8);
+        }
+        self.buffer &= (1 << self.pending) - 1;
+    }
+
+    // into_bytes returns the stream, padded with zero bits to a whole number of bytes.
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        if self.pending > 0 {
+            self.write(0, 8 - self.pending);
+        }
+        self.bytes
+    }
+
+    // into_hex returns the stream as upper case hex text, padded with zero bits to a whole number
+    // of bytes.
+    pub fn into_hex(self) -> String {
+        self.into_bytes().iter().fold(String::new(), |mut hex, byte| {
+            write!(hex, "{:02X}", byte).unwrap();
+            hex
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BitReader;
+
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let mut writer = BitWriter::new();
+        writer.write(6, 3);
+        writer.write(4, 3);
+        writer.write(0b10111, 5);
+        writer.write(0b11110, 5);
+        writer.write(0b00101, 5);
+        assert_eq!(writer.position(), 21);
+        assert_eq!(writer.into_hex(), "D2FE28");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = [(1, 1), (0x123456789ABCDEF0, 64), (0, 7), (u64::MAX, 64), (5, 3)];
+        let mut writer = BitWriter::new();
+        for &(value, n) in &values {
+            writer.write(value, n);
+        }
+        assert_eq!(writer.position(), 139);
+
+        let bytes = writer.into_bytes();
+        assert_eq!(bytes.len(), 18);
+        let mut reader = BitReader::bytes(&bytes);
+        for &(value, n) in &values {
+            assert_eq!(reader.read(n).unwrap(), value);
+        }
+        assert_eq!(reader.read(5).unwrap(), 0);
+        assert!(reader.at_end().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "8 does not fit in 3 bits")]
+    fn test_too_wide() {
+        BitWriter::new().write(8, 3);
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind::NotFound;
+use std::path::Path;
+
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// read_session reads the session cookie from session.txt at the root of the repository, if there
+// is one.
+pub fn read_session(root: &Path) -> Result<Option<String>, String> {
+    let path = root.join("session.txt");
+    match fs::read_to_string(&path) {
+        Err(e) if e.kind() == NotFound => Ok(None),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+        Ok(session) => Ok(Some(String::from(session.trim()))),
+    }
+}
+
+// Client talks to the puzzle server, or to anything at base_url that behaves like it.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+// The puzzle server's verdict on a submitted answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without a hint as to which way.
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Verdict(Verdict),
+    // The answer was not checked because the last one was submitted too recently. Holds the
+    // server's estimate of how long is left to wait, e.g. "42s".
+    TooSoon(Option<String>),
+    // The answer was not checked because the part is locked or already solved.
+    WrongLevel,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session: String::from(session.trim()),
+        }
+    }
+
+    pub fn fetch_input(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let res = ureq::get(&url)
+            .set("cookie", &self.cookie())
+            .call()
+            .map_err(|e| http_error("could not fetch puzzle input", e))?;
+        res.into_string().map_err(|e| format!("could not read puzzle input: {}", e))
+    }
+
+    pub fn submit_answer(&self, year: u32, day: u32, part: Part, answer: &str) -> Result<Response, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let res = ureq::post(&url)
+            .set("cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| http_error("could not submit answer", e))?;
+        let body = res.into_string().map_err(|e| format!("could not read response to answer: {}", e))?;
+        parse_response(&body)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn http_error(context: &str, e: ureq::Error) -> String {
+    match e {
+        ureq::Error::Status(code, res) => format!("{}: {} {}", context, code, res.status_text()),
+        e => format!("{}: {}", context, e),
+    }
+}
+
+// parse_response picks the verdict out of the page that the puzzle server returns for an answer.
+fn parse_response(body: &str) -> Result<Response, String> {
+    if body.contains("That's the right answer") {
+        Ok(Response::Verdict(Verdict::Correct))
+    } else if body.contains("That's not the right answer") {
+        if body.contains("too high") {
+            Ok(Response::Verdict(Verdict::TooHigh))
+        } else if body.contains("too low") {
+            Ok(Response::Verdict(Verdict::TooLow))
+        } else {
+            Ok(Response::Verdict(Verdict::Wrong))
+        }
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body.split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| String::from(wait));
+        Ok(Response::TooSoon(wait))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Response::WrongLevel)
+    } else {
+        Err(String::from("could not find a verdict in the response to answer"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::serve_once;
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once("200 OK", "199\n200\n");
+        assert_eq!(Client::new(&base_url, "abc\n").fetch_input(2021, 1).unwrap(), "199\n200\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request.line, "GET /2021/day/1/input HTTP/1.1");
+        assert_eq!(request.cookie, "session=abc");
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        assert_eq!(Client::new(&base_url, "abc").fetch_input(2021, 26).unwrap_err(),
+                   "could not fetch puzzle input: 404 Not Found");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve_once("200 OK", "<article><p>That's the right answer!</p></article>");
+        assert_eq!(Client::new(&base_url, "abc").submit_answer(2021, 1, Part::Two, "1523").unwrap(),
+                   Response::Verdict(Verdict::Correct));
+
+        let request = server.join().unwrap();
+        assert_eq!(request.line, "POST /2021/day/1/answer HTTP/1.1");
+        assert_eq!(request.cookie, "session=abc");
+        assert_eq!(request.body, "level=2&answer=1523");
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response("That's not the right answer; your answer is too high.").unwrap(),
+                   Response::Verdict(Verdict::TooHigh));
+        assert_eq!(parse_response("That's not the right answer; your answer is too low.").unwrap(),
+                   Response::Verdict(Verdict::TooLow));
+        assert_eq!(parse_response("That's not the right answer.  If you're stuck, ...").unwrap(),
+                   Response::Verdict(Verdict::Wrong));
+        assert_eq!(parse_response("You gave an answer too recently; you have to wait after submitting an \
+                                   answer before trying again.  You have 42s left to wait.").unwrap(),
+                   Response::TooSoon(Some(String::from("42s"))));
+        assert_eq!(parse_response("You don't seem to be solving the right level.  Did you already complete it?").unwrap(),
+                   Response::WrongLevel);
+        assert!(parse_response("<html></html>").is_err());
+    }
+}
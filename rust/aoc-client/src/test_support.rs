@@ -0,0 +1,49 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::thread::JoinHandle;
+
+#[derive(Debug, Default)]
+pub struct Request {
+    pub line: String,
+    pub cookie: String,
+    pub body: String,
+}
+
+// serve_once stands in for the puzzle server: it answers a single request with the given status
+// and body, and returns the request that it saw.
+pub fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request = Request::default();
+        reader.read_line(&mut request.line).unwrap();
+        request.line = request.line.trim().to_string();
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            if header.trim().is_empty() {
+                break;
+            }
+            let header = header.to_lowercase();
+            if let Some(value) = header.strip_prefix("cookie:") {
+                request.cookie = value.trim().to_string();
+            } else if let Some(value) = header.strip_prefix("content-length:") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+        let mut content = vec![0; content_length];
+        reader.read_exact(&mut content).unwrap();
+        request.body = String::from_utf8(content).unwrap();
+
+        write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+        request
+    });
+
+    (base_url, handle)
+}
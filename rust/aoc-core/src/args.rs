@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::env;
+use std::str::FromStr;
+
+// Args is a minimal parser for "aoc <command> --flag value --switch" style command lines.
+pub struct Args {
+    command: Option<String>,
+    options: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    pub fn from_env() -> Result<Self, String> {
+        Args::parse(env::args().skip(1))
+    }
+
+    pub fn parse<I: IntoIterator<Item=String>>(args: I) -> Result<Self, String> {
+        let mut args = args.into_iter().peekable();
+        let mut command = None;
+        let mut options = HashMap::new();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = match args.peek() {
+                        Some(next) if !next.starts_with("--") => args.next(),
+                        _ => None,
+                    };
+                    options.insert(String::from(name), value);
+                }
+                None if command.is_none() => command = Some(arg),
+                None => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+
+        Ok(Args { command, options })
+    }
+
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+
+    // flag reports whether a switch such as --offline was given, with or without a value.
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|v| v.as_deref())
+    }
+
+    pub fn parse_opt<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(v) => v.parse().map(Some).map_err(|_| format!("invalid value for --{}: {}", name, v)),
+        }
+    }
+
+    pub fn require<T: FromStr>(&self, name: &str) -> Result<T, String> {
+        self.parse_opt(name)?.ok_or_else(|| format!("missing required option --{}", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Args {
+        Args::parse(s.split_whitespace().map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = parse("run --day 16 --part 2 --verbose --input in.txt");
+        assert_eq!(args.command(), Some("run"));
+        assert_eq!(args.require::<u32>("day"), Ok(16));
+        assert_eq!(args.parse_opt::<u32>("part"), Ok(Some(2)));
+        assert_eq!(args.get("input"), Some("in.txt"));
+        assert_eq!(args.get("verbose"), None);
+        assert!(args.flag("verbose"));
+        assert!(!args.flag("offline"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let args = parse("run --day x");
+        assert!(args.require::<u32>("day").is_err());
+        assert!(args.require::<u32>("part").is_err());
+        assert!(Args::parse(["run", "extra"].map(String::from)).is_err());
+    }
+}
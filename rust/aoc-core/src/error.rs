@@ -0,0 +1,71 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use crate::Part;
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    // Lines and columns are 1-indexed, like an editor would show them.
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+        found: String,
+    },
+    // The input could be read, but does not make sense in a way that has no line and column,
+    // such as a binary format.
+    Invalid(String),
+    NoSolution(String),
+    MissingPart(Part),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        AocError::Parse { line, column, expected: expected.into(), found: found.into() }
+    }
+
+    pub fn end_of_input(line: usize, expected: impl Into<String>) -> Self {
+        AocError::parse(line, 1, expected, "end of input")
+    }
+
+    pub fn invalid(reason: impl Into<String>) -> Self {
+        AocError::Invalid(reason.into())
+    }
+
+    pub fn no_solution(reason: impl Into<String>) -> Self {
+        AocError::NoSolution(reason.into())
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{}", e),
+            AocError::Parse { line, column, expected, found } => {
+                write!(f, "line {}, column {}: expected {}, found {}", line, column, expected, found)
+            }
+            AocError::Invalid(reason) => write!(f, "invalid input: {}", reason),
+            AocError::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            AocError::MissingPart(part) => write!(f, "no part {}", part),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
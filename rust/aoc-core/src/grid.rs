@@ -0,0 +1,201 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+// A position in a grid, as (row, column).
+pub type Pos = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const SURROUNDING: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+// What happens at the edges of a grid: either they are its bounds, or moving off one edge comes
+// back on at the opposite edge.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Edges {
+    Bounded,
+    Wrapping,
+}
+
+// Grid is a rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    edges: Edges,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, fill: T) -> Self where T: Clone {
+        Grid { height, width, edges: Edges::Bounded, cells: vec![fill; height * width] }
+    }
+
+    // from_cells makes a grid from its cells, row by row.
+    pub fn from_cells(height: usize, width: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), height * width, "{} cells do not make a {}x{} grid", cells.len(), height, width);
+        Grid { height, width, edges: Edges::Bounded, cells }
+    }
+
+    // with_edges sets what neighbours and offsets do at the edges of the grid.
+    pub fn with_edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, (row, column): Pos) -> Option<&T> {
+        if row < self.height && column < self.width {
+            Some(&self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, column): Pos) -> Option<&mut T> {
+        if row < self.height && column < self.width {
+            Some(&mut self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    // positions iterates over every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=Pos> {
+        let width = self.width;
+        (0..self.height * self.width).map(move |i| (i / width, i % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item=&mut T> {
+        self.cells.iter_mut()
+    }
+
+    // offset moves from pos by (rows, columns), returning None if that leaves a bounded grid or
+    // the grid is empty.
+    pub fn offset(&self, pos: Pos, delta: (isize, isize)) -> Option<Pos> {
+        offset(self.height, self.width, self.edges, pos, delta)
+    }
+
+    // neighbours4 iterates over the positions above, left of, right of and below pos.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item=Pos> {
+        self.neighbours(pos, &ORTHOGONAL)
+    }
+
+    // neighbours8 iterates over the positions surrounding pos, including diagonally.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item=Pos> {
+        self.neighbours(pos, &SURROUNDING)
+    }
+
+    // The iterator copies what it needs rather than borrowing the grid, so that the grid can be
+    // updated while iterating over neighbours.
+    fn neighbours(&self, pos: Pos, deltas: &'static [(isize, isize)]) -> impl Iterator<Item=Pos> {
+        let (height, width, edges) = (self.height, self.width, self.edges);
+        deltas.iter().filter_map(move |&delta| offset(height, width, edges, pos, delta))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { height: self.height, width: self.width, edges: self.edges, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+fn offset(height: usize, width: usize, edges: Edges, (row, column): Pos, (rows, columns): (isize, isize)) -> Option<Pos> {
+    match edges {
+        Edges::Bounded => {
+            let row = row.checked_add_signed(rows).filter(|&row| row < height)?;
+            let column = column.checked_add_signed(columns).filter(|&column| column < width)?;
+            Some((row, column))
+        }
+        // An empty grid has nowhere to wrap around to.
+        Edges::Wrapping if height == 0 || width == 0 => None,
+        Edges::Wrapping => Some((
+            (row as isize + rows).rem_euclid(height as isize) as usize,
+            (column as isize + columns).rem_euclid(width as isize) as usize,
+        )),
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, height, width))
+    }
+}
+
+// Grids are displayed a row per line, as they appear in puzzle inputs.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_cells() {
+        let grid = Grid::from_cells(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(grid.map(|&n| if n % 2 == 0 { 'o' } else { ' ' }).to_string(), " o \no o\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 4, 0);
+        assert_eq!(grid.neighbours4((1, 1)).collect::<Vec<Pos>>(), vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<Pos>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 3)).collect::<Vec<Pos>>(), vec![(0, 2), (1, 2), (1, 3)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::new(3, 4, 0).with_edges(Edges::Wrapping);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<Pos>>(), vec![(2, 0), (0, 3), (0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((2, 3)).count(), 8);
+        assert_eq!(grid.offset((2, 3), (1, 1)), Some((0, 0)));
+        assert_eq!(grid.offset((0, 0), (-4, -9)), Some((2, 3)));
+
+        let empty = Grid::new(0, 4, 0).with_edges(Edges::Wrapping);
+        assert_eq!(empty.offset((0, 0), (1, 1)), None);
+        assert_eq!(empty.neighbours8((0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_update_neighbours() {
+        let mut grid = Grid::new(2, 2, 0);
+        for pos in grid.neighbours8((0, 0)) {
+            grid[pos] += 1;
+        }
+        grid.iter_mut().for_each(|cell| *cell *= 2);
+        assert_eq!(grid.to_string(), "02\n22\n");
+    }
+}
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::io;
+use std::io::{BufRead, BufReader, Read};
+
+use crate::{Integer, Line, Result};
+
+// Input is a whole puzzle input held in memory. Input that is already in memory is used in place,
+// and its lines, fields and integers borrow their text from it rather than being copied into
+// Strings.
+pub struct Input<'a> {
+    text: Cow<'a, str>,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Input { text: Cow::Borrowed(text) }
+    }
+
+    // read reads the rest of reader into memory in one go.
+    pub fn read<R: Read + ?Sized>(reader: &mut R) -> Result<Input<'static>> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Ok(Input { text: Cow::Owned(text) })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    // lines iterates over the lines of the input, without their line endings.
+    pub fn lines(&self) -> InputLines<'_> {
+        InputLines { lines: self.text.lines(), number: 0 }
+    }
+
+    // integers iterates over every integer in the input, line by line, ignoring whatever
+    // separates them.
+    pub fn integers<'s, T: Integer + 's>(&'s self, expected: &'static str) -> impl Iterator<Item=Result<T>> + 's {
+        self.lines().flat_map(move |line| line.integers(expected))
+    }
+}
+
+// IntoInput is implemented by everything a solution can take its input from: bytes that are
+// already in memory, and readers, which are read into memory first.
+pub trait IntoInput<'a> {
+    fn into_input(self) -> Result<Input<'a>>;
+}
+
+impl<'a> IntoInput<'a> for Input<'a> {
+    fn into_input(self) -> Result<Input<'a>> {
+        Ok(self)
+    }
+}
+
+impl<'a> IntoInput<'a> for &'a [u8] {
+    fn into_input(self) -> Result<Input<'a>> {
+        let text = std::str::from_utf8(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Input::new(text))
+    }
+}
+
+impl<'a> IntoInput<'a> for &'a str {
+    fn into_input(self) -> Result<Input<'a>> {
+        Ok(Input::new(self))
+    }
+}
+
+impl<R: Read> IntoInput<'_> for BufReader<R> {
+    fn into_input(mut self) -> Result<Input<'static>> {
+        Input::read(&mut self)
+    }
+}
+
+impl<R: BufRead + ?Sized> IntoInput<'_> for &mut R {
+    fn into_input(self) -> Result<Input<'static>> {
+        Input::read(self)
+    }
+}
+
+// InputLines numbers the lines of an Input the same way that Lines numbers the lines of a reader.
+pub struct InputLines<'a> {
+    lines: std::str::Lines<'a>,
+    number: usize,
+}
+
+impl InputLines<'_> {
+    // number is the line number of the most recently read line.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+}
+
+impl<'a> Iterator for InputLines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.number += 1;
+        Some(Line::new(self.number, text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = Input::new("a\r\n\nb\n");
+        let mut lines = input.lines();
+        assert_eq!(lines.next(), Some(Line::new(1, "a")));
+        assert_eq!(lines.next(), Some(Line::new(2, "")));
+        assert_eq!(lines.next(), Some(Line::new(3, "b")));
+        assert_eq!(lines.next(), None);
+        assert_eq!(lines.number(), 3);
+        assert_eq!(Input::new("a\nb").lines().count(), 2);
+        assert!(Input::new("a\nb").lines().all(|line| matches!(line.text, Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_into_input() {
+        let input = BufReader::new("7\n8".as_bytes()).into_input().unwrap();
+        assert_eq!(input.as_str(), "7\n8");
+        let reader: &mut dyn BufRead = &mut "9".as_bytes();
+        assert_eq!(reader.into_input().unwrap().as_bytes(), b"9");
+        assert!(matches!("10".into_input().unwrap().text, Cow::Borrowed(_)));
+        assert!(b"\xff"[..].into_input().is_err());
+    }
+
+    #[test]
+    fn test_integers() {
+        let input = Input::new("1,2\n-3 4\n");
+        assert_eq!(input.integers::<i32>("n").collect::<Result<Vec<_>>>().unwrap(), vec![1, 2, -3, 4]);
+        assert_eq!(input.integers::<u32>("n").nth(2).unwrap().unwrap_err().to_string(),
+                   "line 2, column 1: expected n, found \"-3\"");
+    }
+}
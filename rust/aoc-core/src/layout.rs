@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+// Each day lives in its own crate at rust/y<year>/d<day>, so that several events can live side by
+// side in one workspace.
+
+// day_dir is the path of a day's crate, relative to the workspace root.
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(format!("y{}", year)).join(format!("d{:02}", day))
+}
+
+// workspace_member is day_dir as written in manifests, which always use forward slashes.
+pub fn workspace_member(year: u32, day: u32) -> String {
+    format!("y{}/d{:02}", year, day)
+}
+
+// package_name is the name of a day's crate. Days must be qualified by the year since package names
+// are unique across the workspace.
+pub fn package_name(year: u32, day: u32) -> String {
+    format!("y{}-d{:02}", year, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(day_dir(2022, 5), PathBuf::from("y2022").join("d05"));
+        assert_eq!(workspace_member(2022, 5), "y2022/d05");
+        assert_eq!(package_name(2021, 16), "y2021-d16");
+    }
+}
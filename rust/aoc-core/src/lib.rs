@@ -0,0 +1,17 @@
+pub use args::Args;
+pub use error::{AocError, Result};
+pub use grid::{Edges, Grid, Pos};
+pub use input::{Input, InputLines, IntoInput};
+pub use layout::{day_dir, package_name, workspace_member};
+pub use lines::{Integer, Integers, Line, Lines, lines};
+pub use solution::{Example, Part, Solution};
+pub use sparse::{Bounds, SparseGrid};
+
+mod args;
+mod error;
+mod grid;
+mod input;
+mod layout;
+mod lines;
+mod solution;
+mod sparse;
@@ -0,0 +1,273 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::io;
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::{AocError, Result};
+
+// lines reads the input line by line, keeping track of line numbers for error reporting.
+pub fn lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines { lines: reader.lines(), number: 0 }
+}
+
+pub struct Lines<R> {
+    lines: io::Lines<R>,
+    number: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    // expect returns the next line, or an error if the input ended early.
+    pub fn expect(&mut self, expected: &str) -> Result<Line<'static>> {
+        match self.next() {
+            Some(line) => line,
+            None => Err(AocError::end_of_input(self.number + 1, expected)),
+        }
+    }
+
+    // number is the line number of the most recently read line.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<Line<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.number += 1;
+        Some(text.map(|text| Line::new(self.number, text)).map_err(AocError::from))
+    }
+}
+
+// A line of input that can point at parts of itself when reporting errors. The &str arguments
+// to its methods are expected to be slices of text. Lines read from an Input borrow their text
+// from it, and lines read from a reader own theirs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: Cow<'a, str>,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: impl Into<Cow<'a, str>>) -> Self {
+        Line { number, text: text.into() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    // column returns the 1-indexed column that s starts at.
+    pub fn column(&self, s: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (s.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> AocError {
+        let column = self.column(at);
+        let found = if at.is_empty() && column > self.text.chars().count() {
+            String::from("end of line")
+        } else {
+            format!("{:?}", at)
+        };
+        AocError::parse(self.number, column, expected, found)
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &str, expected: &str) -> Result<T> where T::Err: Debug {
+        s.parse().map_err(|_| self.error(s, expected))
+    }
+
+    pub fn integer<T: Integer>(&self, s: &str, expected: &str) -> Result<T> {
+        self.integer_radix(s, 10, expected)
+    }
+
+    pub fn integer_radix<T: Integer>(&self, s: &str, radix: u32, expected: &str) -> Result<T> {
+        T::from_digits(s, radix).ok_or_else(|| self.error(s, expected))
+    }
+
+    // fields splits the line at every separator.
+    pub fn fields(&self, separator: char) -> std::str::Split<'_, char> {
+        self.text.split(separator)
+    }
+
+    // integers iterates over every integer in the line, ignoring whatever separates them. A "-"
+    // directly before an integer makes it negative. Lines read from an Input are borrowed, so the
+    // iterator only copies the text of lines read from a reader.
+    pub fn integers<T: Integer>(&self, expected: &'static str) -> Integers<'a, T> {
+        Integers { line: self.clone(), offset: 0, expected, integer: PhantomData }
+    }
+
+    pub fn split_once<'s>(&self, s: &'s str, delimiter: &str) -> Result<(&'s str, &'s str)> {
+        s.split_once(delimiter).ok_or_else(|| self.error(s, format!("{:?}", delimiter)))
+    }
+
+    pub fn strip_prefix<'s>(&self, s: &'s str, prefix: &str) -> Result<&'s str> {
+        s.strip_prefix(prefix).ok_or_else(|| self.error(s, format!("{:?}", prefix)))
+    }
+
+    // next pulls the next field from a split of the line, pointing at the end of the line if
+    // there are not enough fields.
+    pub fn next<'s, I: Iterator<Item=&'s str>>(&'s self, fields: &mut I, expected: &str) -> Result<&'s str> {
+        fields.next().ok_or_else(|| self.error(&self.text[self.text.len()..], expected))
+    }
+
+    // end fails if a split of the line has fields left over.
+    pub fn end<'s, I: Iterator<Item=&'s str>>(&'s self, fields: &mut I) -> Result<()> {
+        match fields.next() {
+            None => Ok(()),
+            Some(extra) => Err(self.error(extra, "end of line")),
+        }
+    }
+}
+
+pub struct Integers<'a, T> {
+    line: Line<'a>,
+    offset: usize,
+    expected: &'static str,
+    integer: PhantomData<T>,
+}
+
+impl<T: Integer> Iterator for Integers<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.line.text.as_bytes();
+        let rest = &bytes[self.offset..];
+        let start = self.offset + rest.iter().enumerate().position(|(i, &b)| {
+            b.is_ascii_digit() || (b == b'-' && rest.get(i + 1).is_some_and(u8::is_ascii_digit))
+        })?;
+        let end = bytes[start + 1..].iter().position(|b| !b.is_ascii_digit()).map_or(bytes.len(), |i| start + 1 + i);
+        self.offset = end;
+        Some(self.line.integer(&self.line.text[start..end], self.expected))
+    }
+}
+
+// Integer is implemented by the integer types that can be parsed straight from the text of a
+// line. Unlike FromStr, it takes a radix.
+pub trait Integer: Sized {
+    fn from_digits(s: &str, radix: u32) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn from_digits(s: &str, radix: u32) -> Option<Self> {
+                    // Negative numbers are accumulated downwards so that MIN does not overflow.
+                    #[allow(unused_comparisons)]
+                    let (negative, digits) = match s.strip_prefix('-') {
+                        Some(digits) if <$t>::MIN < 0 => (true, digits),
+                        _ => (false, s.strip_prefix('+').unwrap_or(s)),
+                    };
+                    if digits.is_empty() {
+                        return None;
+                    }
+                    digits.bytes().try_fold(0 as $t, |n, b| {
+                        let digit = (b as char).to_digit(radix)? as $t;
+                        let n = n.checked_mul(radix as $t)?;
+                        if negative { n.checked_sub(digit) } else { n.checked_add(digit) }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(e: AocError) -> (usize, usize, String, String) {
+        match e {
+            AocError::Parse { line, column, expected, found } => (line, column, expected, found),
+            e => panic!("unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut lines = lines("a\nb\n".as_bytes());
+        assert_eq!(lines.expect("a").unwrap(), Line::new(1, "a"));
+        assert_eq!(lines.expect("b").unwrap(), Line::new(2, "b"));
+        assert_eq!(parse_error(lines.expect("c").unwrap_err()),
+                   (3, 1, String::from("c"), String::from("end of input")));
+    }
+
+    #[test]
+    fn test_parse_error_column() {
+        let line = Line::new(4, "12,x4");
+        let (_, rest) = line.split_once(&line.text, ",").unwrap();
+        assert_eq!(parse_error(line.parse::<u32>(rest, "integer").unwrap_err()),
+                   (4, 4, String::from("integer"), String::from("\"x4\"")));
+    }
+
+    #[test]
+    fn test_empty_field() {
+        let line = Line::new(1, "1,,2");
+        let mut fields = line.text.split(',');
+        line.next(&mut fields, "x").unwrap();
+        let y = line.next(&mut fields, "y").unwrap();
+        assert_eq!(parse_error(line.parse::<u32>(y, "y").unwrap_err()),
+                   (1, 3, String::from("y"), String::from("\"\"")));
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let line = Line::new(1, "1 2");
+        let mut fields = line.text.split(' ');
+        assert_eq!(line.next(&mut fields, "x").unwrap(), "1");
+        assert_eq!(line.next(&mut fields, "y").unwrap(), "2");
+        assert_eq!(parse_error(line.next(&mut fields, "z").unwrap_err()),
+                   (1, 4, String::from("z"), String::from("end of line")));
+    }
+
+    #[test]
+    fn test_trailing_fields() {
+        let line = Line::new(1, "1 2");
+        let mut fields = line.text.split(' ');
+        line.next(&mut fields, "x").unwrap();
+        assert_eq!(parse_error(line.end(&mut fields).unwrap_err()).1, 3);
+    }
+
+    #[test]
+    fn test_integer() {
+        let line = Line::new(1, "");
+        assert_eq!(line.integer::<i8>("-128", "n").unwrap(), -128);
+        assert_eq!(line.integer::<u8>("255", "n").unwrap(), 255);
+        assert_eq!(line.integer::<u32>("+12", "n").unwrap(), 12);
+        assert_eq!(line.integer_radix::<u32>("10110", 2, "n").unwrap(), 22);
+        for bad in ["256", "-1", "", "-", "1x", " 1"] {
+            assert!(line.integer::<u8>(bad, "n").is_err(), "{:?}", bad);
+        }
+        assert!(line.integer_radix::<u32>("102", 2, "n").is_err());
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = Line::new(1, "x=-5..10, y=3-4");
+        assert_eq!(line.integers::<i32>("n").collect::<Result<Vec<_>>>().unwrap(), vec![-5, 10, 3, -4]);
+        let line = Line::new(2, "at 1, 99999");
+        assert_eq!(parse_error(line.integers::<u16>("n").nth(1).unwrap().unwrap_err()),
+                   (2, 7, String::from("n"), String::from("\"99999\"")));
+    }
+
+    #[test]
+    fn test_fields() {
+        let line = Line::new(1, "7,4,,9");
+        assert_eq!(line.fields(',').collect::<Vec<_>>(), vec!["7", "4", "", "9"]);
+        let mut fields = line.fields(',');
+        line.next(&mut fields, "a").unwrap();
+        let b = line.next(&mut fields, "b").unwrap();
+        assert_eq!(line.column(b), 3);
+    }
+}
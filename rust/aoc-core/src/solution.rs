@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::{AocError, Result};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: &'static [Part] = &[Part::One, Part::Two];
+
+    // number is the part's number as the puzzle uses it.
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("illegal part {}", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+// An example input from the puzzle description, along with the answers it should produce.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn new(name: &'static str, input: &'static str, part1: Option<&'static str>, part2: Option<&'static str>) -> Self {
+        Example { name, input, part1, part2 }
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+// Solution is implemented by every day so that runners and harnesses can treat them generically.
+pub trait Solution: Sync {
+    fn year(&self) -> u32;
+
+    fn day(&self) -> u32;
+
+    fn title(&self) -> &'static str;
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String>;
+
+    fn part2(&self, _reader: &mut dyn BufRead) -> Result<String> {
+        Err(AocError::MissingPart(Part::Two))
+    }
+
+    // The last day of the event only has one part.
+    fn parts(&self) -> &'static [Part] {
+        Part::BOTH
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    // explain describes step by step how a part works out its answer, for the days that can.
+    fn explain(&self, _part: Part, _reader: &mut dyn BufRead) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn solve(&self, part: Part, reader: &mut dyn BufRead) -> Result<String> {
+        if !self.parts().contains(&part) {
+            return Err(AocError::MissingPart(part));
+        }
+        match part {
+            Part::One => self.part1(reader),
+            Part::Two => self.part2(reader),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        fn year(&self) -> u32 {
+            2021
+        }
+
+        fn day(&self) -> u32 {
+            25
+        }
+
+        fn title(&self) -> &'static str {
+            "Echo"
+        }
+
+        fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+            Ok(reader.lines().next().unwrap()?)
+        }
+
+        fn parts(&self) -> &'static [Part] {
+            &[Part::One]
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Echo.solve(Part::One, &mut "hello".as_bytes()).unwrap(), "hello");
+        assert!(matches!(Echo.solve(Part::Two, &mut "hello".as_bytes()), Err(AocError::MissingPart(Part::Two))));
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// A rectangle of positions, as (row, column), with inclusive edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub top: i64,
+    pub left: i64,
+    pub bottom: i64,
+    pub right: i64,
+}
+
+impl Bounds {
+    pub fn new((top, left): (i64, i64), (bottom, right): (i64, i64)) -> Self {
+        Bounds { top, left, bottom, right }
+    }
+
+    pub fn contains(&self, (row, column): (i64, i64)) -> bool {
+        (self.top..=self.bottom).contains(&row) && (self.left..=self.right).contains(&column)
+    }
+
+    // grow returns the bounds with n more positions on every side.
+    pub fn grow(&self, n: i64) -> Self {
+        Bounds { top: self.top - n, left: self.left - n, bottom: self.bottom + n, right: self.right + n }
+    }
+
+    // union returns the smallest bounds that contain both self and other.
+    pub fn union(&self, other: Bounds) -> Self {
+        Bounds {
+            top: self.top.min(other.top),
+            left: self.left.min(other.left),
+            bottom: self.bottom.max(other.bottom),
+            right: self.right.max(other.right),
+        }
+    }
+
+    // positions iterates over every position in the bounds, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=(i64, i64)> {
+        let Bounds { top, left, bottom, right } = *self;
+        (top..=bottom).flat_map(move |row| (left..=right).map(move |column| (row, column)))
+    }
+}
+
+// SparseGrid is an infinite grid in which every cell holds the background unless set otherwise.
+// Only the cells that differ from the background are stored, and the bounds grow to contain them,
+// so every cell outside the bounds holds the background.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    background: T,
+    cells: HashMap<(i64, i64), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    pub fn new(background: T) -> Self {
+        SparseGrid { background, cells: HashMap::new(), bounds: None }
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    // bounds returns the smallest bounds that contain every cell that differs from the
+    // background, or None if there are no such cells.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, pos: (i64, i64)) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.background)
+    }
+
+    pub fn set(&mut self, pos: (i64, i64), value: T) {
+        if value == self.background {
+            self.cells.remove(&pos);
+            return;
+        }
+        let cell = Bounds::new(pos, pos);
+        self.bounds = Some(self.bounds.map_or(cell, |bounds| bounds.union(cell)));
+        self.cells.insert(pos, value);
+    }
+
+    // len is the number of cells that differ from the background.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // cells iterates over the cells that differ from the background, in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item=((i64, i64), &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    // window iterates over every cell in the window, row by row, including background cells.
+    pub fn window(&self, window: Bounds) -> impl Iterator<Item=((i64, i64), &T)> {
+        window.positions().map(move |pos| (pos, self.get(pos)))
+    }
+
+    // render draws the cells in the window a row per line, using f to pick each cell's character.
+    pub fn render(&self, window: Bounds, f: impl Fn(&T) -> char) -> String {
+        let mut output = String::new();
+        for (pos, value) in self.window(window) {
+            output.push(f(value));
+            if pos.1 == window.right {
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+// SparseGrids are displayed a row per line over their bounds.
+impl<T: Clone + PartialEq + Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(bounds) = self.bounds {
+            for (pos, value) in self.window(bounds) {
+                write!(f, "{}", value)?;
+                if pos.1 == bounds.right {
+                    writeln!(f)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.get((5, -5)), &'.');
+
+        grid.set((5, -5), '#');
+        grid.set((-1, 2), '#');
+        assert_eq!(grid.get((5, -5)), &'#');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some(Bounds::new((-1, -5), (5, 2))));
+
+        // Setting a cell to the background forgets it, but the bounds never shrink.
+        grid.set((5, -5), '.');
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.bounds(), Some(Bounds::new((-1, -5), (5, 2))));
+    }
+
+    #[test]
+    fn test_window() {
+        let mut grid = SparseGrid::new(0);
+        grid.set((0, 0), 1);
+        grid.set((1, 1), 2);
+        let window = grid.bounds().unwrap().grow(1);
+        assert_eq!(window, Bounds::new((-1, -1), (2, 2)));
+        assert_eq!(grid.window(window).count(), 16);
+        assert_eq!(grid.window(window).map(|(_, &v)| v).sum::<i32>(), 3);
+        assert!(window.contains((2, -1)));
+        assert!(!window.contains((3, 0)));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = SparseGrid::new(false);
+        grid.set((0, 1), true);
+        grid.set((1, 2), true);
+        assert_eq!(grid.render(grid.bounds().unwrap(), |&lit| if lit { '#' } else { '.' }), "#.\n.#\n");
+        assert_eq!(grid.render(Bounds::new((0, 0), (1, 2)), |&lit| if lit { '#' } else { '.' }), ".#.\n..#\n");
+
+        let mut grid = SparseGrid::new('.');
+        grid.set((3, 3), 'x');
+        grid.set((4, 5), 'y');
+        assert_eq!(grid.to_string(), "x..\n..y\n");
+        assert_eq!(SparseGrid::new('.').to_string(), "");
+    }
+}
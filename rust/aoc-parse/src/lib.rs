@@ -0,0 +1,254 @@
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+use aoc_core::{AocError, Grid, Integer, Line, Lines, Result};
+
+// A Parser parses the whole of s, which is a slice of line, and reports errors at their position
+// in line. Parsers are built from the combinators below, and any function with the same signature
+// is a parser too.
+pub trait Parser<T>: Fn(&Line, &str) -> Result<T> {}
+
+impl<T, F: Fn(&Line, &str) -> Result<T>> Parser<T> for F {}
+
+// parse_line parses a whole line.
+pub fn parse_line<T>(line: &Line, parser: impl Parser<T>) -> Result<T> {
+    parser(line, &line.text)
+}
+
+// integer parses a decimal integer, with a sign if T is signed.
+pub fn integer<T: Integer>(expected: &'static str) -> impl Parser<T> {
+    move |line: &Line, s: &str| line.integer(s, expected)
+}
+
+// one_of parses one of a fixed set of words.
+pub fn one_of<T: Copy>(choices: &'static [(&'static str, T)], expected: &'static str) -> impl Parser<T> {
+    move |line: &Line, s: &str| {
+        choices.iter()
+            .find(|&&(word, _)| word == s)
+            .map(|&(_, value)| value)
+            .ok_or_else(|| line.error(s, expected))
+    }
+}
+
+// prefixed parses a literal prefix followed by whatever parser parses.
+pub fn prefixed<T>(prefix: &'static str, parser: impl Parser<T>) -> impl Parser<T> {
+    move |line: &Line, s: &str| parser(line, line.strip_prefix(s, prefix)?)
+}
+
+// pair parses two things separated by the first occurrence of separator.
+pub fn pair<A, B>(a: impl Parser<A>, separator: &'static str, b: impl Parser<B>) -> impl Parser<(A, B)> {
+    move |line: &Line, s: &str| {
+        let (left, right) = line.split_once(s, separator)?;
+        Ok((a(line, left)?, b(line, right)?))
+    }
+}
+
+// triple parses three things separated by separator.
+pub fn triple<A, B, C>(a: impl Parser<A>, separator: &'static str, b: impl Parser<B>, c: impl Parser<C>) -> impl Parser<(A, B, C)> {
+    let bc = pair(b, separator, c);
+    move |line: &Line, s: &str| {
+        let (left, rest) = line.split_once(s, separator)?;
+        let a = a(line, left)?;
+        let (b, c) = bc(line, rest)?;
+        Ok((a, b, c))
+    }
+}
+
+// list parses any number of items separated by separator.
+pub fn list<T>(separator: &'static str, item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    move |line: &Line, s: &str| s.split(separator).map(|s| item(line, s)).collect()
+}
+
+// words parses any number of items separated by runs of whitespace.
+pub fn words<T>(item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    move |line: &Line, s: &str| s.split_whitespace().map(|s| item(line, s)).collect()
+}
+
+// chars parses one item per character, which f turns into an item or rejects.
+pub fn chars<T>(expected: &'static str, f: impl Fn(char) -> Option<T>) -> impl Parser<Vec<T>> {
+    move |line: &Line, s: &str| {
+        s.char_indices()
+            .map(|(i, c)| f(c).ok_or_else(|| line.error(&s[i..i + c.len_utf8()], expected)))
+            .collect()
+    }
+}
+
+// range parses an inclusive range such as "-5..10", which must not be descending.
+pub fn range<T: Integer + PartialOrd>(line: &Line, s: &str) -> Result<RangeInclusive<T>> {
+    let (start, end) = line.split_once(s, "..")?;
+    let range = line.integer(start, "range start")?..=line.integer(end, "range end")?;
+    if range.start() > range.end() {
+        return Err(line.error(s, "ascending range"));
+    }
+    Ok(range)
+}
+
+// blocks splits the rest of the input into blocks of lines separated by blank lines.
+pub fn blocks<R: BufRead>(lines: Lines<R>) -> Blocks<R> {
+    Blocks { lines }
+}
+
+pub struct Blocks<R> {
+    lines: Lines<R>,
+}
+
+impl<R: BufRead> Blocks<R> {
+    // number is the line number of the most recently read line.
+    pub fn number(&self) -> usize {
+        self.lines.number()
+    }
+}
+
+impl<R: BufRead> Iterator for Blocks<R> {
+    type Item = Result<Vec<Line<'static>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block = Vec::new();
+        for line in self.lines.by_ref() {
+            match line {
+                Err(e) => return Some(Err(e)),
+                // Runs of blank lines separate blocks just like a single one.
+                Ok(line) if line.is_empty() && block.is_empty() => {}
+                Ok(line) if line.is_empty() => break,
+                Ok(line) => block.push(line),
+            }
+        }
+        if block.is_empty() { None } else { Some(Ok(block)) }
+    }
+}
+
+// char_grid parses the rest of the input as a map of one character per cell, which f turns into
+// a cell or rejects. Every row must be the same length.
+pub fn char_grid<R: BufRead, T>(mut lines: Lines<R>, expected: &'static str, f: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+    let row = chars(expected, f);
+    let mut cells = Vec::new();
+    let mut width = 0;
+    for line in lines.by_ref() {
+        let line = line?;
+        let start = cells.len();
+        cells.extend(parse_line(&line, &row)?);
+
+        let length = cells.len() - start;
+        if start == 0 {
+            width = length;
+        }
+        if length == 0 || length != width {
+            return Err(line.error(&line.text, format!("row of {} columns", width.max(1))));
+        }
+    }
+
+    if cells.is_empty() {
+        return Err(AocError::end_of_input(lines.number() + 1, expected));
+    }
+    Ok(Grid::from_cells(cells.len() / width, width, cells))
+}
+
+// digit_grid parses the rest of the input as a map of one decimal digit per cell.
+pub fn digit_grid<R: BufRead>(lines: Lines<R>, expected: &'static str) -> Result<Grid<u8>> {
+    char_grid(lines, expected, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::lines;
+
+    use super::*;
+
+    fn parse<T>(s: &str, parser: impl Parser<T>) -> Result<T> {
+        parse_line(&Line::new(1, s), parser)
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(parse("-42", integer::<i32>("n")).unwrap(), -42);
+        assert_eq!(parse("-42", integer::<u32>("n")).unwrap_err().to_string(), "line 1, column 1: expected n, found \"-42\"");
+    }
+
+    #[test]
+    fn test_one_of() {
+        let switch = one_of(&[("on", true), ("off", false)], "on or off");
+        assert!(parse("on", &switch).unwrap());
+        assert!(!parse("off", &switch).unwrap());
+        assert_eq!(parse("of", &switch).unwrap_err().to_string(), "line 1, column 1: expected on or off, found \"of\"");
+    }
+
+    #[test]
+    fn test_pair() {
+        let point = pair(integer::<u32>("x"), ",", integer::<u32>("y"));
+        let segment = pair(&point, " -> ", &point);
+        assert_eq!(parse("0,9 -> 5,9", &segment).unwrap(), ((0, 9), (5, 9)));
+        assert_eq!(parse("0,9 -> 5;9", &segment).unwrap_err().to_string(), "line 1, column 8: expected \",\", found \"5;9\"");
+        assert_eq!(parse("0,9 -> 5,x", &segment).unwrap_err().to_string(), "line 1, column 10: expected y, found \"x\"");
+    }
+
+    #[test]
+    fn test_triple() {
+        let vector = triple(integer::<i32>("x"), ",", integer::<i32>("y"), integer::<i32>("z"));
+        assert_eq!(parse("404,-588,-901", &vector).unwrap(), (404, -588, -901));
+        assert_eq!(parse("404,-588", &vector).unwrap_err().to_string(), "line 1, column 5: expected \",\", found \"-588\"");
+        assert_eq!(parse("1,2,3,4", &vector).unwrap_err().to_string(), "line 1, column 5: expected z, found \"3,4\"");
+        assert_eq!(parse("x,2,z", &vector).unwrap_err().to_string(), "line 1, column 1: expected x, found \"x\"");
+    }
+
+    #[test]
+    fn test_range() {
+        let cuboid = triple(prefixed("x=", range::<i32>), ",", prefixed("y=", range::<i32>), prefixed("z=", range::<i32>));
+        assert_eq!(parse("x=-5..10,y=1..1,z=0..2", &cuboid).unwrap(), (-5..=10, 1..=1, 0..=2));
+        assert_eq!(parse("x=-5..10,y=1..1,w=0..2", &cuboid).unwrap_err().to_string(),
+                   "line 1, column 17: expected \"z=\", found \"w=0..2\"");
+        assert_eq!(parse("x=10..-5,y=1..1,z=0..2", &cuboid).unwrap_err().to_string(),
+                   "line 1, column 3: expected ascending range, found \"10..-5\"");
+        assert_eq!(parse("x=-10...-5,y=1..1,z=0..2", &cuboid).unwrap_err().to_string(),
+                   "line 1, column 8: expected range end, found \".-5\"");
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(parse("7,4,9", list(",", integer::<u32>("number"))).unwrap(), vec![7, 4, 9]);
+        assert_eq!(parse(" 8  2 23", words(integer::<u32>("number"))).unwrap(), vec![8, 2, 23]);
+        assert_eq!(parse("7,,9", list(",", integer::<u32>("number"))).unwrap_err().to_string(),
+                   "line 1, column 3: expected number, found \"\"");
+        assert_eq!(parse("NbC", chars("element", |c| c.is_ascii_uppercase().then_some(c))).unwrap_err().to_string(),
+                   "line 1, column 2: expected element, found \"b\"");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let mut blocks = blocks(lines("a\nb\n\n\nc\n\n".as_bytes()));
+        let texts = |block: Vec<Line>| block.into_iter().map(|line| (line.number, line.text.into_owned())).collect::<Vec<_>>();
+        assert_eq!(texts(blocks.next().unwrap().unwrap()), vec![(1, String::from("a")), (2, String::from("b"))]);
+        assert_eq!(texts(blocks.next().unwrap().unwrap()), vec![(5, String::from("c"))]);
+        assert!(blocks.next().is_none());
+        assert_eq!(blocks.number(), 6);
+    }
+
+    fn digits(s: &str) -> Result<Grid<u8>> {
+        digit_grid(lines(s.as_bytes()), "digit")
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_grid_errors() {
+        assert_eq!(digits("123\n4x6").unwrap_err().to_string(), "line 2, column 2: expected digit, found \"x\"");
+        assert_eq!(digits("123\n45").unwrap_err().to_string(), "line 2, column 1: expected row of 3 columns, found \"45\"");
+        assert_eq!(digits("123\n\n456").unwrap_err().to_string(), "line 2, column 1: expected row of 3 columns, found end of line");
+        assert_eq!(digits("").unwrap_err().to_string(), "line 1, column 1: expected digit, found end of input");
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid(lines("#.\n.#".as_bytes()), "pixel", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }).unwrap();
+        assert_eq!(grid.positions().filter(|&pos| grid[pos]).collect::<Vec<_>>(), vec![(0, 0), (1, 1)]);
+    }
+}
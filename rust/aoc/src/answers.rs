@@ -0,0 +1,155 @@
+use std::fs;
+use std::io::ErrorKind::NotFound;
+use std::path::Path;
+
+use aoc_client::Verdict;
+use aoc_core::Part;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Answers is the history of every answer submitted to the puzzle server, as kept in answers.toml.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "attempt")]
+    pub attempts: Vec<Attempt>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    #[serde(serialize_with = "serialize_part", deserialize_with = "deserialize_part")]
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+// What the history says about an answer that has not been submitted yet.
+#[derive(Debug, PartialEq)]
+pub enum Status<'a> {
+    Unknown,
+    // The answer has already been accepted.
+    Accepted,
+    // A different answer has already been accepted, so something has changed since.
+    Changed(&'a Attempt),
+    // The attempt shows that the answer is wrong, either because it is the same answer or
+    // because the answer is past a too high or too low bound.
+    Wrong(&'a Attempt),
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Err(e) if e.kind() == NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+            Ok(contents) => toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = toml::to_string(self).map_err(|e| format!("{}: {}", path.display(), e))?;
+        fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    pub fn status(&self, year: u32, day: u32, part: Part, answer: &str) -> Status<'_> {
+        let mut attempts = self.attempts.iter().filter(|a| a.year == year && a.day == day && a.part == part);
+        if let Some(accepted) = attempts.clone().find(|a| a.verdict == Verdict::Correct) {
+            return if accepted.answer == answer { Status::Accepted } else { Status::Changed(accepted) };
+        }
+        attempts.find(|a| a.rules_out(answer)).map_or(Status::Unknown, Status::Wrong)
+    }
+}
+
+impl Attempt {
+    fn rules_out(&self, answer: &str) -> bool {
+        if self.answer == answer {
+            return self.verdict != Verdict::Correct;
+        }
+        match (self.verdict, self.answer.parse::<i128>(), answer.parse::<i128>()) {
+            (Verdict::TooHigh, Ok(bound), Ok(answer)) => answer >= bound,
+            (Verdict::TooLow, Ok(bound), Ok(answer)) => answer <= bound,
+            _ => false,
+        }
+    }
+}
+
+// Parts are written as the numbers that the puzzle server uses for them.
+fn serialize_part<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u32(part.number())
+}
+
+fn deserialize_part<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Part, D::Error> {
+    u32::deserialize(deserializer)?.to_string().parse().map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    fn attempt(part: Part, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt { year: 2021, day: 1, part, answer: String::from(answer), verdict }
+    }
+
+    fn answers(attempts: &[Attempt]) -> Answers {
+        Answers { attempts: attempts.to_vec() }
+    }
+
+    #[test]
+    fn test_status_unknown() {
+        let answers = answers(&[attempt(Part::One, "100", Verdict::Wrong)]);
+        assert_eq!(answers.status(2021, 1, Part::One, "101"), Status::Unknown);
+        assert_eq!(answers.status(2021, 1, Part::Two, "100"), Status::Unknown);
+        assert_eq!(answers.status(2021, 2, Part::One, "100"), Status::Unknown);
+    }
+
+    #[test]
+    fn test_status_known_wrong() {
+        let answers = answers(&[attempt(Part::One, "abc", Verdict::Wrong)]);
+        assert_eq!(answers.status(2021, 1, Part::One, "abc"), Status::Wrong(&answers.attempts[0]));
+    }
+
+    #[test]
+    fn test_status_bounds() {
+        let high = attempt(Part::One, "100", Verdict::TooHigh);
+        let low = attempt(Part::One, "10", Verdict::TooLow);
+        let answers = answers(&[high.clone(), low.clone()]);
+        assert_eq!(answers.status(2021, 1, Part::One, "100"), Status::Wrong(&high));
+        assert_eq!(answers.status(2021, 1, Part::One, "150"), Status::Wrong(&high));
+        assert_eq!(answers.status(2021, 1, Part::One, "10"), Status::Wrong(&low));
+        assert_eq!(answers.status(2021, 1, Part::One, "-5"), Status::Wrong(&low));
+        assert_eq!(answers.status(2021, 1, Part::One, "50"), Status::Unknown);
+        assert_eq!(answers.status(2021, 1, Part::One, "fifty"), Status::Unknown);
+    }
+
+    #[test]
+    fn test_status_accepted() {
+        let correct = attempt(Part::Two, "42", Verdict::Correct);
+        let answers = answers(&[attempt(Part::Two, "41", Verdict::TooLow), correct.clone()]);
+        assert_eq!(answers.status(2021, 1, Part::Two, "42"), Status::Accepted);
+        assert_eq!(answers.status(2021, 1, Part::Two, "41"), Status::Changed(&correct));
+        assert_eq!(answers.status(2021, 1, Part::Two, "43"), Status::Changed(&correct));
+    }
+
+    #[test]
+    fn test_load_save() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.toml", process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+
+        let mut answers = Answers::default();
+        answers.record(attempt(Part::One, "100", Verdict::TooHigh));
+        answers.record(attempt(Part::Two, "multi\nline", Verdict::Correct));
+        answers.save(&path).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("[[attempt]]\nyear = 2021\nday = 1\npart = 1\nanswer = \"100\"\nverdict = \"too-high\"\n"));
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+        fs::remove_file(&path).unwrap();
+    }
+}
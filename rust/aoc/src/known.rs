@@ -0,0 +1,74 @@
+use std::fs;
+use std::io::ErrorKind::NotFound;
+use std::path::Path;
+
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+
+// KnownAnswers are a day's answers for its real input, as kept in the day's known_answers.toml.
+// They catch a refactor that changes an answer, even one that the examples don't cover.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl KnownAnswers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Err(e) if e.kind() == NotFound => Ok(KnownAnswers::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+            Ok(contents) => toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = toml::to_string(self).map_err(|e| format!("{}: {}", path.display(), e))?;
+        fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn test_load_save() {
+        let path = env::temp_dir().join(format!("aoc-known-answers-{}.toml", process::id()));
+        let _ = fs::remove_file(&path);
+        let mut known = KnownAnswers::load(&path).unwrap();
+        assert_eq!(known, KnownAnswers::default());
+        assert_eq!(known.get(Part::One), None);
+
+        known.set(Part::One, String::from("4512"));
+        known.save(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "part1 = \"4512\"\n");
+
+        // Some answers, like the letters drawn by 2021 day 13, span several lines.
+        known.set(Part::Two, String::from("#..#\n####\n#..#\n"));
+        known.save(&path).unwrap();
+        let loaded = KnownAnswers::load(&path).unwrap();
+        assert_eq!(loaded.get(Part::One), Some("4512"));
+        assert_eq!(loaded.get(Part::Two), Some("#..#\n####\n#..#\n"));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+pub use aoc_core::{Example, Part, Solution};
+
+pub mod answers;
+pub mod known;
+
+// solutions lists every day of every year in order.
+pub fn solutions() -> Vec<&'static dyn Solution> {
+    vec![
+        &y2021_d01::Day,
+        &y2021_d02::Day,
+        &y2021_d03::Day,
+        &y2021_d04::Day,
+        &y2021_d05::Day,
+        &y2021_d06::Day,
+        &y2021_d07::Day,
+        &y2021_d08::Day,
+        &y2021_d09::Day,
+        &y2021_d10::Day,
+        &y2021_d11::Day,
+        &y2021_d12::Day,
+        &y2021_d13::Day,
+        &y2021_d14::Day,
+        &y2021_d15::Day,
+        &y2021_d16::Day,
+        &y2021_d17::Day,
+        &y2021_d18::Day,
+        &y2021_d19::Day,
+        &y2021_d20::Day,
+        &y2021_d21::Day,
+        &y2021_d22::Day,
+        &y2021_d23::Day,
+        &y2021_d24::Day,
+        &y2021_d25::Day,
+    ]
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Solution> {
+    solutions().into_iter().find(|s| s.year() == year && s.day() == day)
+}
+
+// latest_year is the most recent year with any solutions.
+pub fn latest_year() -> u32 {
+    solutions().iter().map(|s| s.year()).max().unwrap()
+}
+
+// input_path is the input.txt that lives alongside the day's crate.
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    workspace_dir().join(aoc_core::day_dir(year, day)).join("input.txt")
+}
+
+// known_answers_path is the known_answers.toml that lives alongside the day's crate.
+pub fn known_answers_path(year: u32, day: u32) -> PathBuf {
+    workspace_dir().join(aoc_core::day_dir(year, day)).join("known_answers.toml")
+}
+
+fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
@@ -0,0 +1,167 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use aoc::answers::{Answers, Attempt, Status};
+use aoc::known::KnownAnswers;
+use aoc::{Part, Solution};
+use aoc_client::{Client, DEFAULT_BASE_URL, Response, Verdict};
+use aoc_core::Args;
+
+const USAGE: &str = "usage: aoc run [--year <year>] --day <day> [--part <part>] [--input <path>] [--explain]
+       aoc record [--year <year>] --day <day> [--part <part>] [--input <path>]
+       aoc submit [--year <year>] --day <day> --part <part> [--input <path>] [--base-url <url>]";
+
+fn main() {
+    let result = Args::from_env().and_then(|args| match args.command() {
+        Some("run") => run(&args),
+        Some("record") => record(&args),
+        Some("submit") => submit(&args),
+        Some(command) => Err(format!("unknown command {}\n{}", command, USAGE)),
+        None => Err(String::from(USAGE)),
+    });
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(2);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let solution = find_solution(args)?;
+    let input = input_path(args, solution);
+    let answers = Answers::load(&answers_path())?;
+
+    for part in parts(args, solution)? {
+        if args.flag("explain") {
+            print!("{}", explain(solution, part, &input)?);
+        }
+        let answer = solve(solution, part, &input)?;
+        println!("{}", answer);
+        warn_if_changed(&answers, solution, part, &answer);
+    }
+
+    Ok(())
+}
+
+// record saves the answers for the real input as the day's known answers, which the known
+// answers test then checks every day against.
+fn record(args: &Args) -> Result<(), String> {
+    let solution = find_solution(args)?;
+    let input = input_path(args, solution);
+    let answers = Answers::load(&answers_path())?;
+    let path = aoc::known_answers_path(solution.year(), solution.day());
+    let mut known = KnownAnswers::load(&path)?;
+
+    for part in parts(args, solution)? {
+        let answer = solve(solution, part, &input)?;
+        println!("{}", answer);
+        warn_if_changed(&answers, solution, part, &answer);
+        if let Some(previous) = known.get(part).filter(|&previous| previous != answer) {
+            eprintln!("warning: {} day {} part {}: replacing known answer {:?}",
+                      solution.year(), solution.day(), part, previous);
+        }
+        known.set(part, answer);
+    }
+
+    known.save(&path)
+}
+
+fn parts(args: &Args, solution: &dyn Solution) -> Result<Vec<Part>, String> {
+    match args.parse_opt::<Part>("part")? {
+        Some(part) => Ok(vec![part]),
+        None => Ok(solution.parts().to_vec()),
+    }
+}
+
+// A refactor should not change an answer that the puzzle server has accepted.
+fn warn_if_changed(answers: &Answers, solution: &dyn Solution, part: Part, answer: &str) {
+    if let Status::Changed(accepted) = answers.status(solution.year(), solution.day(), part, answer) {
+        eprintln!("warning: {} day {} part {}: answer {:?} differs from the accepted answer {:?}",
+                  solution.year(), solution.day(), part, answer, accepted.answer);
+    }
+}
+
+fn submit(args: &Args) -> Result<(), String> {
+    let solution = find_solution(args)?;
+    let (year, day) = (solution.year(), solution.day());
+    let part: Part = args.require("part")?;
+    let answer = solve(solution, part, &input_path(args, solution))?;
+    let path = answers_path();
+    let mut answers = Answers::load(&path)?;
+
+    match answers.status(year, day, part, &answer) {
+        Status::Unknown => {}
+        Status::Accepted => {
+            println!("{}: already accepted", answer);
+            return Ok(());
+        }
+        Status::Changed(accepted) => {
+            return Err(format!("not submitting {:?}: the accepted answer is {:?}", answer, accepted.answer));
+        }
+        Status::Wrong(attempt) if attempt.answer == answer => {
+            return Err(format!("not submitting {:?}: it was already {}", answer, attempt.verdict));
+        }
+        Status::Wrong(attempt) => {
+            return Err(format!("not submitting {:?}: it must be {} since {:?} was {}",
+                               answer, attempt.verdict, attempt.answer, attempt.verdict));
+        }
+    }
+
+    let root = root();
+    let session = aoc_client::read_session(&root)?
+        .ok_or_else(|| String::from("session.txt is required to submit answers"))?;
+    let client = Client::new(args.get("base-url").unwrap_or(DEFAULT_BASE_URL), &session);
+    let verdict = match client.submit_answer(year, day, part, &answer)? {
+        Response::Verdict(verdict) => verdict,
+        Response::TooSoon(Some(wait)) => return Err(format!("answer submitted too recently, {} left to wait", wait)),
+        Response::TooSoon(None) => return Err(String::from("answer submitted too recently")),
+        Response::WrongLevel => return Err(format!("{} day {} part {} is locked or already solved", year, day, part)),
+    };
+
+    answers.record(Attempt { year, day, part, answer: answer.clone(), verdict });
+    answers.save(&path)?;
+    match verdict {
+        Verdict::Correct => {
+            println!("{}: {}", answer, verdict);
+            Ok(())
+        }
+        _ => Err(format!("{}: {}", answer, verdict)),
+    }
+}
+
+fn find_solution(args: &Args) -> Result<&'static dyn Solution, String> {
+    let year: u32 = args.parse_opt("year")?.unwrap_or_else(aoc::latest_year);
+    let day: u32 = args.require("day")?;
+    aoc::find(year, day).ok_or_else(|| format!("no solution for {} day {}", year, day))
+}
+
+fn input_path(args: &Args, solution: &dyn Solution) -> PathBuf {
+    match args.get("input") {
+        Some(path) => PathBuf::from(path),
+        None => aoc::input_path(solution.year(), solution.day()),
+    }
+}
+
+fn solve(solution: &dyn Solution, part: Part, input: &Path) -> Result<String, String> {
+    let mut reader = BufReader::new(File::open(input).map_err(|e| format!("{}: {}", input.display(), e))?);
+    solution.solve(part, &mut reader)
+        .map_err(|e| format!("{} day {} part {}: {}", solution.year(), solution.day(), part, e))
+}
+
+fn explain(solution: &dyn Solution, part: Part, input: &Path) -> Result<String, String> {
+    let mut reader = BufReader::new(File::open(input).map_err(|e| format!("{}: {}", input.display(), e))?);
+    let explanation = solution.explain(part, &mut reader)
+        .map_err(|e| format!("{} day {} part {}: {}", solution.year(), solution.day(), part, e))?;
+    explanation.ok_or_else(|| format!("{} day {} cannot explain its answers", solution.year(), solution.day()))
+}
+
+// root is the root of the repository, where session.txt and answers.toml live.
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().parent().unwrap().to_path_buf()
+}
+
+fn answers_path() -> PathBuf {
+    root().join("answers.toml")
+}
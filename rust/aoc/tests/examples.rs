@@ -0,0 +1,28 @@
+use aoc::Part;
+
+#[test]
+fn test_examples() {
+    for solution in aoc::solutions() {
+        for example in solution.examples() {
+            for &part in solution.parts() {
+                if let Some(want) = example.answer(part) {
+                    let got = solution.solve(part, &mut example.input.as_bytes()).unwrap();
+                    assert_eq!(got, want, "{} day {} part {} example {}", solution.year(), solution.day(), part, example.name);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_solutions_in_order() {
+    let days = aoc::solutions().iter().map(|s| (s.year(), s.day())).collect::<Vec<(u32, u32)>>();
+    let mut sorted = days.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(days, sorted);
+
+    let days_2021 = days.iter().filter(|(year, _)| *year == 2021).map(|&(_, day)| day).collect::<Vec<u32>>();
+    assert_eq!(days_2021, (1..=25).collect::<Vec<u32>>());
+    assert_eq!(aoc::find(2021, 25).unwrap().parts(), &[Part::One]);
+}
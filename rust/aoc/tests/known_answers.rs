@@ -0,0 +1,67 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, Write};
+use std::thread;
+
+use aoc::known::KnownAnswers;
+use aoc::Solution;
+
+// test_known_answers runs every part against its real input and compares the answer with the
+// one recorded by `aoc record`. Days without an input.txt are skipped, since inputs are not
+// shared, but they are listed on every run so that a passing test never hides how little it
+// checked. A part with an input and no recorded answer fails. Every failing day is reported
+// rather than just the first.
+#[test]
+fn test_known_answers() {
+    let solutions = aoc::solutions();
+    let results = thread::scope(|scope| {
+        let handles = solutions.iter().map(|&solution| scope.spawn(move || check(solution))).collect::<Vec<_>>();
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<Result<Option<usize>, Vec<String>>>>()
+    });
+
+    let total = solutions.iter().map(|s| s.parts().len()).sum::<usize>();
+    let checked = results.iter().map(|result| result.as_ref().map_or(0, |checked| checked.unwrap_or(0))).sum::<usize>();
+    let skipped = solutions.iter().zip(&results)
+        .filter(|(_, result)| matches!(result, Ok(None)))
+        .map(|(solution, _)| format!("{} day {}", solution.year(), solution.day()))
+        .collect::<Vec<String>>();
+    let failures = results.into_iter().filter_map(Result::err).flatten().collect::<Vec<String>>();
+
+    // The test harness captures eprintln! from passing tests, so the report is written to stderr
+    // directly.
+    let mut report = format!("checked {} of {} parts against known answers\n", checked, total);
+    if !skipped.is_empty() {
+        report += &format!("no input.txt, so no answers checked for: {}\n", skipped.join(", "));
+    }
+    io::stderr().write_all(report.as_bytes()).unwrap();
+    assert!(failures.is_empty(), "{} parts failed:\n{}", failures.len(), failures.join("\n"));
+}
+
+// check returns the number of parts that were checked, None if the day has no input, or a
+// description of each part that failed.
+fn check(solution: &dyn Solution) -> Result<Option<usize>, Vec<String>> {
+    let (year, day) = (solution.year(), solution.day());
+    let input = aoc::input_path(year, day);
+    if !input.exists() {
+        return Ok(None);
+    }
+    let known = KnownAnswers::load(&aoc::known_answers_path(year, day)).map_err(|e| vec![e])?;
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for &part in solution.parts() {
+        let Some(want) = known.get(part) else {
+            let hint = format!("run `aoc record --year {} --day {}`", year, day);
+            failures.push(format!("{} day {} part {}: unrecorded, {}", year, day, part, hint));
+            continue;
+        };
+        let mut reader = BufReader::new(File::open(&input).unwrap());
+        match solution.solve(part, &mut reader) {
+            Ok(got) if got == want => checked += 1,
+            Ok(got) => failures.push(format!("{} day {} part {}: got {:?}, want {:?}", year, day, part, got, want)),
+            Err(e) => failures.push(format!("{} day {} part {}: {}", year, day, part, e)),
+        }
+    }
+
+    if failures.is_empty() { Ok(Some(checked)) } else { Err(failures) }
+}
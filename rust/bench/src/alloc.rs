@@ -0,0 +1,77 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ops::Sub;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+// Counting wraps the system allocator and counts every allocation, so that benchmarks can report
+// how much a part allocates as well as how long it takes.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    // Growing a Vec or String reallocates, which costs about as much as a fresh allocation.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+fn count(size: usize) {
+    COUNT.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size as u64, Relaxed);
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+}
+
+// allocations returns the allocations made so far by every thread. Only the difference between
+// two calls is meaningful.
+pub fn allocations() -> Allocations {
+    Allocations { count: COUNT.load(Relaxed), bytes: BYTES.load(Relaxed) }
+}
+
+impl Sub for Allocations {
+    type Output = Allocations;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Allocations { count: self.count - rhs.count, bytes: self.bytes - rhs.bytes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+
+    #[test]
+    fn test_allocations() {
+        let before = allocations();
+        let v = black_box(Vec::<u64>::with_capacity(100));
+        let after = allocations() - before;
+        drop(v);
+
+        // Other tests may be allocating at the same time, so this is only a lower bound.
+        assert!(after.count >= 1);
+        assert!(after.bytes >= 800);
+    }
+}
@@ -0,0 +1,93 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+// Benchmark is the result of benchmarking one part of one day, as saved in a baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Benchmark {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub benchmarks: Vec<Benchmark>,
+}
+
+// Comparison is a benchmark's change in median time since the baseline, as a fraction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Comparison {
+    // The baseline has no result for the benchmark.
+    New,
+    Change { change: f64, regressed: bool },
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        serde_json::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self).map_err(|e| format!("{}: {}", path.display(), e))?;
+        fs::write(path, contents + "\n").map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    // compare compares a benchmark with the baseline, which it regresses if its median is more
+    // than threshold slower. threshold is a fraction, so 0.1 allows 10% slower.
+    pub fn compare(&self, benchmark: &Benchmark, threshold: f64) -> Comparison {
+        let previous = self.benchmarks.iter()
+            .find(|b| (b.year, b.day, b.part) == (benchmark.year, benchmark.day, benchmark.part));
+        match previous {
+            None => Comparison::New,
+            Some(previous) => {
+                let change = benchmark.median_ns as f64 / previous.median_ns.max(1) as f64 - 1.0;
+                Comparison::Change { change, regressed: change > threshold }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    fn benchmark(day: u32, median_ns: u64) -> Benchmark {
+        Benchmark { year: 2021, day, part: 1, median_ns, p95_ns: median_ns * 2, allocations: 3, allocated_bytes: 64 }
+    }
+
+    fn change(comparison: Comparison) -> (i64, bool) {
+        match comparison {
+            Comparison::Change { change, regressed } => ((change * 100.0).round() as i64, regressed),
+            Comparison::New => panic!("no baseline"),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline { benchmarks: vec![benchmark(1, 1000), benchmark(2, 1000)] };
+        assert_eq!(change(baseline.compare(&benchmark(1, 1050), 0.1)), (5, false));
+        assert_eq!(change(baseline.compare(&benchmark(2, 1500), 0.1)), (50, true));
+        assert_eq!(change(baseline.compare(&benchmark(2, 500), 0.1)), (-50, false));
+        assert_eq!(baseline.compare(&benchmark(3, 1000), 0.1), Comparison::New);
+    }
+
+    #[test]
+    fn test_load_save() {
+        let path = env::temp_dir().join(format!("bench-baseline-{}.json", process::id()));
+        let baseline = Baseline { benchmarks: vec![benchmark(1, 1000)] };
+        baseline.save(&path).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("\"median_ns\": 1000"));
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+        fs::remove_file(&path).unwrap();
+    }
+}
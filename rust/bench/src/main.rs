@@ -0,0 +1,158 @@
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc::{Part, Solution};
+use aoc_core::Args;
+
+use crate::alloc::{allocations, Counting};
+use crate::baseline::{Baseline, Benchmark, Comparison};
+use crate::stats::{format_duration, Stats};
+
+mod alloc;
+mod baseline;
+mod stats;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+const USAGE: &str = "usage: bench [--year <year>] [--day <day>] [--part <part>] [--warmup <runs>] [--samples <n>] \
+[--save <path>] [--compare <path>] [--threshold <percent>]";
+
+// Each sample runs a part enough times to take at least this long, so that fast parts are not
+// lost in the resolution of the clock.
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(1);
+
+struct Config {
+    warmup: u32,
+    samples: u32,
+}
+
+fn main() {
+    let result = Args::from_env().and_then(|args| {
+        if args.command().is_some() {
+            return Err(String::from(USAGE));
+        }
+        bench(&args)
+    });
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    }
+}
+
+// bench benchmarks every selected part that has an input, and returns whether they are all
+// within the threshold of the baseline.
+fn bench(args: &Args) -> Result<bool, String> {
+    let year: Option<u32> = args.parse_opt("year")?;
+    let day: Option<u32> = args.parse_opt("day")?;
+    let part: Option<Part> = args.parse_opt("part")?;
+    let config = Config {
+        warmup: args.parse_opt("warmup")?.unwrap_or(3),
+        samples: args.parse_opt("samples")?.unwrap_or(20),
+    };
+    if config.samples == 0 {
+        return Err(String::from("--samples must be at least 1"));
+    }
+    let baseline = args.get("compare").map(|path| Baseline::load(Path::new(path))).transpose()?;
+    let threshold = args.parse_opt::<f64>("threshold")?.unwrap_or(10.0) / 100.0;
+
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build, use cargo run --release");
+    }
+
+    let mut results = Baseline::default();
+    let mut ok = true;
+    for solution in aoc::solutions() {
+        if year.is_some_and(|year| year != solution.year()) || day.is_some_and(|day| day != solution.day()) {
+            continue;
+        }
+        let path = aoc::input_path(solution.year(), solution.day());
+        let Ok(input) = fs::read(&path) else {
+            eprintln!("skipping {} day {}: no {}", solution.year(), solution.day(), path.display());
+            continue;
+        };
+
+        for &p in solution.parts().iter().filter(|&&p| part.is_none_or(|part| part == p)) {
+            let benchmark = run(solution, p, &input, &config)?;
+            let comparison = baseline.as_ref().map(|baseline| baseline.compare(&benchmark, threshold));
+            ok &= !matches!(comparison, Some(Comparison::Change { regressed: true, .. }));
+            println!("{}", report(&benchmark, comparison));
+            results.benchmarks.push(benchmark);
+        }
+    }
+
+    if let Some(path) = args.get("save") {
+        results.save(Path::new(path))?;
+    }
+    Ok(ok)
+}
+
+// run times a part after some warmup runs. Each sample is the mean of a batch of runs.
+fn run(solution: &dyn Solution, part: Part, input: &[u8], config: &Config) -> Result<Benchmark, String> {
+    let solve = || {
+        let mut reader = black_box(input);
+        solution.solve(part, &mut reader)
+            .map_err(|e| format!("{} day {} part {}: {}", solution.year(), solution.day(), part, e))
+    };
+
+    // A part allocates the same on every run, so a single run is enough to count it.
+    let before = allocations();
+    let start = Instant::now();
+    black_box(solve()?);
+    let mut elapsed = start.elapsed();
+    let allocated = allocations() - before;
+
+    for _ in 0..config.warmup {
+        let start = Instant::now();
+        black_box(solve()?);
+        elapsed = start.elapsed();
+    }
+
+    let runs = (MIN_SAMPLE_TIME.as_nanos() / elapsed.as_nanos().max(1)).max(1) as u32;
+    let mut samples = Vec::with_capacity(config.samples as usize);
+    for _ in 0..config.samples {
+        let start = Instant::now();
+        for _ in 0..runs {
+            black_box(solve()?);
+        }
+        samples.push(start.elapsed() / runs);
+    }
+
+    let stats = Stats::new(&samples);
+    Ok(Benchmark {
+        year: solution.year(),
+        day: solution.day(),
+        part: part.number(),
+        median_ns: stats.median.as_nanos() as u64,
+        p95_ns: stats.p95.as_nanos() as u64,
+        allocations: allocated.count,
+        allocated_bytes: allocated.bytes,
+    })
+}
+
+fn report(benchmark: &Benchmark, comparison: Option<Comparison>) -> String {
+    let mut line = format!("{} day {:02} part {}  median {:>9}  p95 {:>9}  {:>8} allocations {:>10} bytes",
+                           benchmark.year, benchmark.day, benchmark.part,
+                           format_duration(Duration::from_nanos(benchmark.median_ns)),
+                           format_duration(Duration::from_nanos(benchmark.p95_ns)),
+                           benchmark.allocations, benchmark.allocated_bytes);
+    match comparison {
+        None => {}
+        Some(Comparison::New) => line.push_str("  new"),
+        Some(Comparison::Change { change, regressed }) => {
+            line.push_str(&format!("  {:+.1}%", change * 100.0));
+            if regressed {
+                line.push_str(" REGRESSED");
+            }
+        }
+    }
+    line
+}
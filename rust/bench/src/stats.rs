@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+// Stats summarizes the time taken by each sample of a benchmark.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        Stats { median: percentile(&sorted, 50), p95: percentile(&sorted, 95) }
+    }
+}
+
+// percentile returns the nearest-rank percentile of sorted samples: the smallest sample that at
+// least p percent of samples are no greater than.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+// format_duration renders a duration with about three significant figures, e.g. "1.23ms".
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos() as f64;
+    if ns < 1e3 {
+        format!("{}ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.2}µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.2}ms", ns / 1e6)
+    } else {
+        format!("{:.2}s", ns / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+
+        let samples = millis(&(1..=100).rev().collect::<Vec<u64>>());
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let stats = Stats::new(&millis(&[7]));
+        assert_eq!(stats, Stats { median: Duration::from_millis(7), p95: Duration::from_millis(7) });
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(812)), "812ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_346)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_230)), "1.23ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+}
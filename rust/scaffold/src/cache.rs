@@ -0,0 +1,152 @@
+use std::fs;
+use std::io;
+use std::io::ErrorKind::NotFound;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+// Cache is a content-addressed store of puzzle inputs. Each input is stored once under objects/,
+// named by the hash of its contents, and index/<year>/<day>/<session hash> points at the input
+// that a session was given. Only a hash of the session is written, never the session itself.
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Cache { root: root.into() }
+    }
+
+    // get returns the input cached for the given session.
+    pub fn get(&self, year: u32, day: u32, session: &str) -> io::Result<Option<String>> {
+        let entry = self.index_dir(year, day).join(session_hash(session));
+        match read_optional(&entry)? {
+            Some(hash) => self.read_object(hash.trim()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    // get_any returns an input cached for any session, for machines that have no session of their
+    // own. Sessions are tried in a fixed order so that repeated runs agree.
+    pub fn get_any(&self, year: u32, day: u32) -> io::Result<Option<String>> {
+        let mut entries = match fs::read_dir(self.index_dir(year, day)) {
+            Err(e) if e.kind() == NotFound => return Ok(None),
+            Err(e) => return Err(e),
+            Ok(entries) => entries.map(|entry| entry.map(|e| e.path())).collect::<io::Result<Vec<PathBuf>>>()?,
+        };
+        entries.sort();
+
+        match entries.first() {
+            Some(entry) => self.read_object(fs::read_to_string(entry)?.trim()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn put(&self, year: u32, day: u32, session: &str, input: &str) -> io::Result<()> {
+        let hash = hash(input.as_bytes());
+        let object = self.object_path(&hash);
+        if !object.exists() {
+            write_atomic(&object, input)?;
+        }
+        write_atomic(&self.index_dir(year, day).join(session_hash(session)), &hash)
+    }
+
+    fn read_object(&self, hash: &str) -> io::Result<String> {
+        let input = fs::read_to_string(self.object_path(hash))?;
+        if self::hash(input.as_bytes()) != hash {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("cached input {} is corrupt", hash)));
+        }
+        Ok(input)
+    }
+
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.root.join("objects").join(&hash[..2]).join(&hash[2..])
+    }
+
+    fn index_dir(&self, year: u32, day: u32) -> PathBuf {
+        self.root.join("index").join(year.to_string()).join(format!("{:02}", day))
+    }
+}
+
+// session_hash identifies a session without revealing it.
+pub fn session_hash(session: &str) -> String {
+    hash(session.trim().as_bytes())[..16].to_string()
+}
+
+fn hash(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn read_optional(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == NotFound => Ok(None),
+        Err(e) => Err(e),
+        Ok(contents) => Ok(Some(contents)),
+    }
+}
+
+// write_atomic writes through a temporary file so that an interrupted write never leaves a
+// truncated input behind.
+fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    fn temp_cache(name: &str) -> Cache {
+        let root = env::temp_dir().join(format!("scaffold-cache-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&root);
+        Cache::new(root)
+    }
+
+    #[test]
+    fn test_put_get() {
+        let cache = temp_cache("put-get");
+        assert_eq!(cache.get(2021, 1, "abc").unwrap(), None);
+
+        cache.put(2021, 1, "abc", "199\n200\n").unwrap();
+        assert_eq!(cache.get(2021, 1, "abc").unwrap().as_deref(), Some("199\n200\n"));
+        assert_eq!(cache.get(2021, 1, "def").unwrap(), None);
+        assert_eq!(cache.get(2021, 2, "abc").unwrap(), None);
+        assert_eq!(cache.get_any(2021, 1).unwrap().as_deref(), Some("199\n200\n"));
+        assert_eq!(cache.get_any(2022, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_content_addressed() {
+        let cache = temp_cache("content-addressed");
+        cache.put(2021, 1, "abc", "same").unwrap();
+        cache.put(2021, 1, "def", "same").unwrap();
+
+        let objects = fs::read_dir(cache.root.join("objects")).unwrap().count();
+        assert_eq!(objects, 1);
+        assert_eq!(fs::read_to_string(cache.object_path(&hash(b"same"))).unwrap(), "same");
+    }
+
+    #[test]
+    fn test_session_not_stored() {
+        let cache = temp_cache("session-not-stored");
+        cache.put(2021, 1, "secret-session", "input").unwrap();
+
+        let entry = fs::read_dir(cache.index_dir(2021, 1)).unwrap().next().unwrap().unwrap();
+        assert_eq!(entry.file_name().to_str().unwrap(), session_hash("secret-session"));
+        assert!(!entry.file_name().to_str().unwrap().contains("secret"));
+    }
+
+    #[test]
+    fn test_corrupt_object() {
+        let cache = temp_cache("corrupt-object");
+        cache.put(2021, 1, "abc", "input").unwrap();
+        fs::write(cache.object_path(&hash(b"input")), "tampered").unwrap();
+
+        assert_eq!(cache.get(2021, 1, "abc").unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
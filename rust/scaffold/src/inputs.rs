@@ -0,0 +1,113 @@
+use std::fs;
+use std::io::ErrorKind::NotFound;
+use std::path::PathBuf;
+
+use aoc_client::Client;
+
+use crate::cache::Cache;
+
+// Inputs finds puzzle inputs, preferring the cache and a local directory of inputs over the network.
+pub struct Inputs {
+    pub cache: Cache,
+    pub session: Option<String>,
+    // A directory of inputs laid out as <year>/<day>.txt, e.g. 2021/01.txt.
+    pub input_dir: Option<PathBuf>,
+    pub base_url: String,
+    pub offline: bool,
+}
+
+impl Inputs {
+    pub fn load(&self, year: u32, day: u32) -> Result<String, String> {
+        if let Some(session) = &self.session {
+            if let Some(input) = self.cache.get(year, day, session).map_err(|e| cache_error(year, day, e))? {
+                return Ok(input);
+            }
+        }
+
+        if let Some(input_dir) = &self.input_dir {
+            let path = input_dir.join(year.to_string()).join(format!("{:02}.txt", day));
+            match fs::read_to_string(&path) {
+                Err(e) if e.kind() == NotFound => {}
+                Err(e) => return Err(format!("{}: {}", path.display(), e)),
+                Ok(input) => return Ok(input),
+            }
+        }
+
+        if self.offline {
+            return self.cache.get_any(year, day)
+                .map_err(|e| cache_error(year, day, e))?
+                .ok_or_else(|| format!("no cached input for {} day {} and --offline was given", year, day));
+        }
+
+        let session = self.session.as_deref()
+            .ok_or_else(|| String::from("session.txt is required to fetch puzzle inputs"))?;
+        let input = Client::new(&self.base_url, session).fetch_input(year, day)?;
+        self.cache.put(year, day, session, &input).map_err(|e| cache_error(year, day, e))?;
+        Ok(input)
+    }
+}
+
+fn cache_error(year: u32, day: u32, e: std::io::Error) -> String {
+    format!("input cache for {} day {}: {}", year, day, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use aoc_client::test_support::serve_once;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("scaffold-inputs-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn inputs(name: &str, base_url: &str) -> Inputs {
+        Inputs {
+            cache: Cache::new(temp_dir(name).join("cache")),
+            session: Some(String::from("abc")),
+            input_dir: None,
+            base_url: String::from(base_url),
+            offline: false,
+        }
+    }
+
+    #[test]
+    fn test_load_caches_fetched_input() {
+        let (base_url, server) = serve_once("200 OK", "input");
+        let inputs = inputs("caches-fetched", &base_url);
+        assert_eq!(inputs.load(2021, 1).unwrap(), "input");
+        server.join().unwrap();
+
+        // The stand-in only answers once, so this must come from the cache.
+        assert_eq!(inputs.load(2021, 1).unwrap(), "input");
+    }
+
+    #[test]
+    fn test_load_offline() {
+        let mut inputs = inputs("offline", "http://127.0.0.1:1");
+        inputs.offline = true;
+        inputs.session = None;
+        assert!(inputs.load(2021, 1).unwrap_err().contains("--offline"));
+
+        inputs.cache.put(2021, 1, "someone-else", "cached").unwrap();
+        assert_eq!(inputs.load(2021, 1).unwrap(), "cached");
+    }
+
+    #[test]
+    fn test_load_input_dir() {
+        let dir = temp_dir("input-dir");
+        fs::create_dir_all(dir.join("2021")).unwrap();
+        fs::write(dir.join("2021").join("05.txt"), "local").unwrap();
+
+        let mut inputs = inputs("input-dir-cache", "http://127.0.0.1:1");
+        inputs.offline = true;
+        inputs.input_dir = Some(dir);
+        assert_eq!(inputs.load(2021, 5).unwrap(), "local");
+        assert!(inputs.load(2021, 6).is_err());
+    }
+}
@@ -1,18 +1,57 @@
 use std::env;
 use std::fs;
-use std::fs::File;
 use std::io::ErrorKind::NotFound;
-use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::process::Command;
 
+use aoc_client::DEFAULT_BASE_URL;
+use aoc_core::Args;
+
+use crate::cache::Cache;
+use crate::inputs::Inputs;
+
+mod cache;
+mod inputs;
+mod register;
+
+const USAGE: &str = "usage: scaffold --year <year> --day <day> [--example <path>] [--expected1 <answer>] \
+[--expected2 <answer>] [--offline] [--input-dir <path>] [--base-url <url>]";
+
 fn main() {
-    let day = env::args().nth(1).ok_or("expected day argument").unwrap().parse().unwrap();
-    let path = find_root().join("rust").join(format!("d{:02}", day));
-    with_path(&path, scaffold_cargo_project, path_is_new);
-    with_path(&path.join("src").join("main.rs"), scaffold_main, file_contains(include_str!("templates/main.rs.default.tpl")));
-    with_path(&path.join("src").join("testdata"), scaffold_testdata, path_is_new);
-    with_path(&path.join("input.txt"), scaffold_input(2021, day), path_is_new);
+    let result = Args::from_env().and_then(|args| {
+        if args.command().is_some() {
+            return Err(String::from(USAGE));
+        }
+        let year = args.require("year")?;
+        let day = args.require("day")?;
+        let example = match args.get("example") {
+            Some(path) => fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?,
+            None => String::new(),
+        };
+        let expected = [args.get("expected1").map(String::from), args.get("expected2").map(String::from)];
+        let root = find_root();
+        let inputs = Inputs {
+            cache: Cache::new(root.join(".aoc-cache")),
+            session: aoc_client::read_session(&root)?,
+            input_dir: args.get("input-dir").map(PathBuf::from),
+            base_url: String::from(args.get("base-url").unwrap_or(DEFAULT_BASE_URL)),
+            offline: args.flag("offline"),
+        };
+
+        let workspace = root.join("rust");
+        let path = workspace.join(aoc_core::day_dir(year, day));
+        with_path(&path, scaffold_cargo_project(year, day), path_is_new);
+        with_path(&path.join("src").join("lib.rs"), scaffold_lib(year, day, expected), file_contains(include_str!("templates/lib.rs.default.tpl")));
+        with_path(&path.join("src").join("testdata"), scaffold_testdata(example), path_is_new);
+        register_day(&workspace, year, day)?;
+        scaffold_input(&path.join("input.txt"), &inputs, year, day)
+    });
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(2);
+    }
 }
 
 fn find_root() -> PathBuf {
@@ -28,63 +67,129 @@ fn find_root() -> PathBuf {
     try_path
 }
 
-fn with_path(path: &PathBuf, f: impl Fn(&PathBuf), reason: impl Fn(&PathBuf) -> bool) {
+fn with_path(path: &Path, f: impl Fn(&Path), reason: impl Fn(&Path) -> bool) {
     if reason(path) {
         f(path)
     }
 }
 
-fn path_is_new(path: &PathBuf) -> bool {
+fn path_is_new(path: &Path) -> bool {
     match fs::metadata(path) {
-        Err(e) if e.kind() == NotFound => { return true; }
+        Err(e) if e.kind() == NotFound => { true }
         Err(e) => { panic!("{}", e) }
-        _ => { return false; }
-    };
+        _ => { false }
+    }
 }
 
-fn file_contains<'a>(substr: &'a str) -> Box<dyn Fn(&PathBuf) -> bool + 'a> {
+fn file_contains<'a>(substr: &'a str) -> Box<dyn Fn(&Path) -> bool + 'a> {
     Box::new(move |path| {
         let contents = match fs::read_to_string(path) {
             Err(e) if e.kind() == NotFound => { return false }
             Err(e) => { panic!("{}", e) }
             Ok(c) => { c }
         };
-        return contents.contains(substr);
+        contents.contains(substr)
     })
 }
 
-fn scaffold_cargo_project(path: &PathBuf) {
-    Command::new("cargo")
-        .args(["new", path.file_name().unwrap().to_str().unwrap()])
-        .current_dir(path.parent().unwrap())
-        .output().unwrap();
+fn scaffold_cargo_project(year: u32, day: u32) -> Box<dyn Fn(&Path)> {
+    Box::new(move |path| {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        Command::new("cargo")
+            .args(["new", "--lib", "--edition", "2021", "--name", &aoc_core::package_name(year, day)])
+            .arg(path.file_name().unwrap())
+            .current_dir(path.parent().unwrap())
+            .output().unwrap();
+    })
 }
 
-fn scaffold_input(year: u32, day: u32) -> Box<dyn Fn(&PathBuf)> {
+fn scaffold_input(path: &Path, inputs: &Inputs, year: u32, day: u32) -> Result<(), String> {
+    if !path_is_new(path) {
+        return Ok(());
+    }
+    let input = inputs.load(year, day)?;
+    fs::write(path, input).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn scaffold_lib(year: u32, day: u32, expected: [Option<String>; 2]) -> Box<dyn Fn(&Path)> {
     Box::new(move |path| {
-        let input = fetch_input(year, day);
-        let mut file = File::create(path).unwrap();
-        file.write_all(input.as_bytes()).unwrap();
+        fs::write(path, render_lib(year, day, &expected)).unwrap();
     })
 }
 
-fn scaffold_main(path: &PathBuf) {
-    fs::write(path, include_bytes!("templates/main.rs.tpl")).unwrap();
+// render_lib fills in the lib.rs template. Expected answers become the example's answers and the
+// assertions in its tests, which fail until the day is solved.
+fn render_lib(year: u32, day: u32, expected: &[Option<String>; 2]) -> String {
+    let answer = |expected: &Option<String>| match expected {
+        Some(answer) => format!("Some({:?})", answer),
+        None => String::from("None"),
+    };
+    let assertion = |expected: &Option<String>| format!("{:?}", expected.as_deref().unwrap_or(""));
+
+    include_str!("templates/lib.rs.tpl")
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{example1}}", &answer(&expected[0]))
+        .replace("{{example2}}", &answer(&expected[1]))
+        .replace("{{expected1}}", &assertion(&expected[0]))
+        .replace("{{expected2}}", &assertion(&expected[1]))
 }
 
-fn scaffold_testdata(path: &PathBuf) {
-    fs::create_dir(path).unwrap();
-    fs::write(path.join("basic.txt"), "").unwrap();
+fn scaffold_testdata(example: String) -> Box<dyn Fn(&Path)> {
+    Box::new(move |path| {
+        fs::create_dir(path).unwrap();
+        fs::write(path.join("basic.txt"), &example).unwrap();
+    })
 }
 
-fn fetch_input(year: u32, day: u32) -> String {
-    let session = fs::read_to_string(find_root().join("session.txt")).unwrap();
-    let session = session.trim();
-    let res = minreq::get(format!("https://adventofcode.com/{}/day/{}/input", year, day))
-        .with_header("cookie", format!("session={}", session))
-        .send().unwrap();
-    if res.status_code != 200 {
-        panic!("Could not fetch puzzle input: {}", res.reason_phrase)
+// register_day adds the day to the workspace, gives it the dependencies that the template uses, and
+// adds it to the runner.
+fn register_day(workspace: &Path, year: u32, day: u32) -> Result<(), String> {
+    let member = aoc_core::workspace_member(year, day);
+    let package = aoc_core::package_name(year, day);
+
+    update_file(&workspace.join(aoc_core::day_dir(year, day)).join("Cargo.toml"), |manifest| {
+        let manifest = register::insert_line(manifest, "[dependencies]", "[", "aoc-core = { path = \"../../aoc-core\" }")?;
+        register::insert_line(&manifest, "[dependencies]", "[", "aoc-parse = { path = \"../../aoc-parse\" }")
+    })?;
+    update_file(&workspace.join("Cargo.toml"), |manifest| {
+        register::register_member(manifest, &member)
+    })?;
+    update_file(&workspace.join("aoc").join("Cargo.toml"), |manifest| {
+        register::insert_line(manifest, "[dependencies]", "[", &format!("{} = {{ path = \"../{}\" }}", package, member))
+    })?;
+    update_file(&workspace.join("aoc").join("src").join("lib.rs"), |source| {
+        register::insert_line(source, "    vec![", "    ]", &format!("        &{}::Day,", package.replace('-', "_")))
+    })
+}
+
+fn update_file(path: &Path, f: impl Fn(&str) -> Result<String, String>) -> Result<(), String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let updated = f(&contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+    if updated != contents {
+        fs::write(path, updated).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_lib() {
+        let lib = render_lib(2022, 5, &[Some(String::from("CMZ")), None]);
+        assert!(lib.contains("fn year(&self) -> u32 {\n        2022\n    }"));
+        assert!(lib.contains("fn day(&self) -> u32 {\n        5\n    }"));
+        assert!(lib.contains("include_str!(\"testdata/basic.txt\"), Some(\"CMZ\"), None)"));
+        assert!(lib.contains("assert_eq!(part1(BufReader::new(BASIC)).unwrap(), \"CMZ\")"));
+        assert!(lib.contains("assert_eq!(part2(BufReader::new(BASIC)).unwrap(), \"\")"));
+        assert!(!lib.contains("{{"));
+    }
+
+    #[test]
+    fn test_render_lib_escapes_answers() {
+        let lib = render_lib(2021, 13, &[None, Some(String::from("#\"#\n"))]);
+        assert!(lib.contains("None, Some(\"#\\\"#\\n\"))"));
     }
-    String::from(res.as_str().unwrap())
 }
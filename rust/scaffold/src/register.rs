@@ -0,0 +1,120 @@
+// Scaffolded days are registered by editing manifests and sources as text, so that their
+// formatting and comments are left alone.
+
+// register_member adds a member to the workspace manifest's members list, rewriting the list with
+// one member per line in sorted order. `cargo new` adds new members to the list itself, but on the
+// same line as the last member.
+pub fn register_member(manifest: &str, member: &str) -> Result<String, String> {
+    let lines = manifest.lines().collect::<Vec<&str>>();
+    let start = lines.iter().position(|line| line.trim_start().starts_with("members = ["))
+        .ok_or_else(|| String::from("workspace manifest has no members list"))?;
+    let end = start + lines[start..].iter().position(|line| line.trim_end().ends_with(']'))
+        .ok_or_else(|| String::from("workspace members list is not closed"))?;
+
+    let mut members = lines[start..=end].join("\n")
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .map(String::from)
+        .collect::<Vec<String>>();
+    members.push(String::from(member));
+    members.sort();
+    members.dedup();
+
+    let mut output = lines[..start].iter().map(|line| line.to_string()).collect::<Vec<String>>();
+    output.push(String::from("members = ["));
+    output.extend(members.iter().map(|member| format!("    \"{}\",", member)));
+    output.push(String::from("]"));
+    output.extend(lines[end + 1..].iter().map(|line| line.to_string()));
+    Ok(output.join("\n") + "\n")
+}
+
+// insert_line inserts line into the sorted block of lines that follows the line starting with
+// start, and ends before the next line starting with end or at the end of the file. Nothing changes
+// if the block already has the line.
+pub fn insert_line(contents: &str, start: &str, end: &str, line: &str) -> Result<String, String> {
+    let mut lines = contents.lines().collect::<Vec<&str>>();
+    let start_index = lines.iter().position(|l| l.starts_with(start))
+        .ok_or_else(|| format!("could not find {:?}", start))?;
+    let end_index = lines[start_index + 1..].iter()
+        .position(|l| l.starts_with(end))
+        .map_or(lines.len(), |i| start_index + 1 + i);
+
+    let block = &lines[start_index + 1..end_index];
+    if block.contains(&line) {
+        return Ok(String::from(contents));
+    }
+
+    // Blank lines and comments at the end of the block stay where they are.
+    let block_end = block.iter().rposition(|l| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+        .map_or(start_index + 1, |i| start_index + 2 + i);
+    let index = lines[start_index + 1..block_end].iter()
+        .position(|&l| l > line)
+        .map_or(block_end, |i| start_index + 1 + i);
+
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str = "[workspace]
+resolver = \"2\"
+members = [
+    \"aoc\",
+    \"y2021/d01\",
+]
+
+[profile.test]
+opt-level = 3
+";
+
+    #[test]
+    fn test_register_member() {
+        assert_eq!(register_member(WORKSPACE, "y2022/d05").unwrap(), "[workspace]
+resolver = \"2\"
+members = [
+    \"aoc\",
+    \"y2021/d01\",
+    \"y2022/d05\",
+]
+
+[profile.test]
+opt-level = 3
+");
+        assert_eq!(register_member(WORKSPACE, "y2021/d01").unwrap(), WORKSPACE);
+    }
+
+    #[test]
+    fn test_register_member_after_cargo_new() {
+        let manifest = WORKSPACE.replace("\"y2021/d01\",", "\"y2021/d01\", \"y2022/d05\",");
+        assert_eq!(register_member(&manifest, "y2022/d05").unwrap(), register_member(WORKSPACE, "y2022/d05").unwrap());
+    }
+
+    #[test]
+    fn test_insert_line() {
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\naoc-core = { path = \"../aoc-core\" }\ny2021-d01 = { path = \"../y2021/d01\" }\ny2023-d01 = { path = \"../y2023/d01\" }\n";
+        let line = "y2022-d05 = { path = \"../y2022/d05\" }";
+        let output = insert_line(manifest, "[dependencies]", "[", line).unwrap();
+        assert_eq!(output, "[package]\nname = \"aoc\"\n\n[dependencies]\naoc-core = { path = \"../aoc-core\" }\ny2021-d01 = { path = \"../y2021/d01\" }\ny2022-d05 = { path = \"../y2022/d05\" }\ny2023-d01 = { path = \"../y2023/d01\" }\n");
+        assert_eq!(insert_line(&output, "[dependencies]", "[", line).unwrap(), output);
+    }
+
+    #[test]
+    fn test_insert_line_before_trailing_blank() {
+        let source = "    vec![\n        &y2021_d01::Day,\n    ]\n";
+        assert_eq!(insert_line(source, "    vec![", "    ]", "        &y2022_d05::Day,").unwrap(),
+                   "    vec![\n        &y2021_d01::Day,\n        &y2022_d05::Day,\n    ]\n");
+
+        let manifest = "[dependencies]\n\n[dev-dependencies]\n";
+        assert_eq!(insert_line(manifest, "[dependencies]", "[", "aoc-core = { path = \"../../aoc-core\" }").unwrap(),
+                   "[dependencies]\naoc-core = { path = \"../../aoc-core\" }\n\n[dev-dependencies]\n");
+    }
+
+    #[test]
+    fn test_insert_line_missing_start() {
+        assert!(insert_line("[package]\n", "[dependencies]", "[", "a = 1").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::BufRead;
+
+use aoc_core::{Example, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        {{year}}
+    }
+
+    fn day(&self) -> u32 {
+        {{day}}
+    }
+
+    fn title(&self) -> &'static str {
+        ""
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), {{example1}}, {{example2}}),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    for line in lines(reader) {
+        let _line = line?;
+    }
+
+    Ok(String::new())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    for line in lines(reader) {
+        let _line = line?;
+    }
+
+    Ok(String::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), {{expected1}})
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), {{expected2}})
+    }
+}
@@ -0,0 +1,86 @@
+use std::io::BufRead;
+
+use aoc_core::{Example, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Sonar Sweep"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("7"), Some("5")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let mut prev = i32::MAX;
+    let mut total = 0;
+    for line in lines(reader) {
+        let line = line?;
+        let cur = line.parse(&line.text, "depth")?;
+        if cur > prev {
+            total += 1;
+        }
+        prev = cur;
+    }
+    Ok(total.to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let mut total = 0;
+    let mut buf = [0; 3];
+    for (i, line) in lines(reader).enumerate() {
+        let line = line?;
+        let cur = line.parse(&line.text, "depth")?;
+        if i >= 3 && cur > buf[i % 3] {
+            total += 1;
+        }
+        buf[i % 3] = cur;
+    }
+    Ok(total.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "7")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "5")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("199\n2x0".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected depth, found \"2x0\"");
+    }
+}
@@ -0,0 +1,109 @@
+use std::io::BufRead;
+
+use aoc_core::{Example, Line, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Dive!"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("150"), Some("900")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let mut distance = 0;
+    let mut depth = 0;
+    for line in lines(reader) {
+        match parse_command(&line?)? {
+            Command::Forward(value) => distance += value,
+            Command::Down(value) => depth += value,
+            Command::Up(value) => depth -= value,
+        }
+    }
+    Ok((distance * depth).to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let mut aim = 0;
+    let mut horiz = 0;
+    let mut depth = 0;
+    for line in lines(reader) {
+        match parse_command(&line?)? {
+            Command::Forward(value) => {
+                horiz += value;
+                depth += aim * value;
+            }
+            Command::Down(value) => aim += value,
+            Command::Up(value) => aim -= value,
+        }
+    }
+    Ok((horiz * depth).to_string())
+}
+
+enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+fn parse_command(line: &Line) -> Result<Command> {
+    let mut fields = line.text.split_whitespace();
+    let direction = line.next(&mut fields, "direction")?;
+    let value = line.parse(line.next(&mut fields, "distance")?, "distance")?;
+    line.end(&mut fields)?;
+    match direction {
+        "forward" => Ok(Command::Forward(value)),
+        "down" => Ok(Command::Down(value)),
+        "up" => Ok(Command::Up(value)),
+        _ => Err(line.error(direction, "forward, down or up")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "150")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "900")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("forward 5\nbackward 5".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected forward, down or up, found \"backward\"");
+        let err = part1(BufReader::new("forward".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 8: expected distance, found end of line");
+    }
+}
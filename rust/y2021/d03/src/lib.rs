@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Input, IntoInput, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Binary Diagnostic"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("198"), Some("230")),
+            Example::new("dense", include_str!("testdata/dense.txt"), None, Some("12")),
+        ]
+    }
+}
+
+pub fn part1<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let (numbers, num_digits) = parse_input(&input.into_input()?)?;
+
+    let gamma = (0..num_digits).fold(0, |accum, index_rl| {
+        if is_majority_ones_at_index(&numbers, index_rl) {
+            accum | 1 << index_rl
+        } else {
+            accum
+        }
+    });
+
+    let epsilon = bitwise_invert(gamma, num_digits);
+
+    Ok((gamma * epsilon).to_string())
+}
+
+pub fn part2<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let (mut numbers, num_digits) = parse_input(&input.into_input()?)?;
+
+    numbers.sort();
+
+    let oxygen = calculate_rating(&numbers, num_digits, Ordering::is_gt);
+    let co2 = calculate_rating(&numbers, num_digits, Ordering::is_le);
+
+    Ok((oxygen * co2).to_string())
+}
+
+// Every number must have as many digits as the first, which must leave room for the shifts that
+// build a mask of them.
+fn parse_input(input: &Input) -> Result<(Vec<u32>, u32)> {
+    let mut num_digits = 0;
+
+    let numbers = input.lines()
+        .map(|line| {
+            let width = line.text.len() as u32;
+            if num_digits == 0 {
+                if !(1..=31).contains(&width) {
+                    return Err(line.error(&line.text, "binary number of 1 to 31 digits"));
+                }
+                num_digits = width;
+            } else if width != num_digits {
+                return Err(line.error(&line.text, format!("binary number of {} digits", num_digits)));
+            }
+            line.integer_radix::<u32>(&line.text, 2, "binary number")
+        })
+        .collect::<Result<Vec<u32>>>()?;
+
+    if numbers.is_empty() {
+        return Err(AocError::end_of_input(1, "binary number"));
+    }
+
+    Ok((numbers, num_digits))
+}
+
+fn is_majority_ones_at_index(numbers: &[u32], index_rl: u32) -> bool {
+    let num_ones = numbers.iter().fold(0, |accum, &number| {
+        accum + (number >> index_rl & 1)
+    });
+
+    (num_ones * 2) as usize > numbers.len()
+}
+
+fn bitwise_invert(value: u32, num_digits: u32) -> u32 {
+    (u32::MAX ^ value) & ((1 << num_digits) - 1)
+}
+
+fn calculate_rating(numbers: &[u32], num_digits: u32, should_follow_zeros: fn(zeros_compared_to_ones: Ordering) -> bool) -> u32 {
+    let mut candidates = numbers;
+    let mut mask = 1 << (num_digits - 1);
+
+    // The candidates share every digit above mask, so being sorted puts those with a zero at mask
+    // first. When they all have the same digit there, every one of them is kept. Equal numbers
+    // are still candidates once the digits run out, and any of them will do.
+    while candidates.len() > 1 && mask > 0 {
+        let ones_index = candidates.partition_point(|number| number & mask == 0);
+
+        if 0 < ones_index && ones_index < candidates.len() {
+            let zeros_compared_to_ones = (ones_index * 2).cmp(&candidates.len());
+
+            candidates = if should_follow_zeros(zeros_compared_to_ones) {
+                &candidates[0..ones_index]
+            } else {
+                &candidates[ones_index..candidates.len()]
+            };
+        }
+
+        mask >>= 1;
+    }
+
+    candidates[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "198")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "230")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("00100\n11120".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected binary number, found \"11120\"");
+        let err = part1(BufReader::new("00100\n1110".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected binary number of 5 digits, found \"1110\"");
+        let wide = "1".repeat(32);
+        let err = part2(BufReader::new(wide.as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), format!("line 1, column 1: expected binary number of 1 to 31 digits, found {:?}", wide));
+    }
+
+    #[test]
+    fn test_part2_same_digits() {
+        assert_eq!(part2(BufReader::new("11\n11\n".as_bytes())).unwrap(), "9");
+        assert_eq!(part2(BufReader::new("10\n11\n".as_bytes())).unwrap(), "6");
+    }
+
+    #[test]
+    fn test_part2_dense() {
+        assert_eq!(part2(BufReader::new(include_str!("testdata/dense.txt").as_bytes())).unwrap(), "12")
+    }
+}
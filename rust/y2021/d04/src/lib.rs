@@ -0,0 +1,159 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Line, lines, Result, Solution};
+use aoc_parse::{blocks, integer, list, parse_line, words};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Giant Squid"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("4512"), Some("1924")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let (mut boards, numbers) = parse_input(reader)?;
+
+    for n in numbers {
+        for board in &mut boards {
+            if board.mark(n) {
+                return Ok((board.sum_unmarked() * n).to_string());
+            }
+        }
+    }
+
+    Err(AocError::no_solution("no board wins"))
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let (mut boards, numbers) = parse_input(reader)?;
+
+    let mut solved_boards = vec![false; boards.len()];
+    let mut remainder = boards.len();
+
+    for n in numbers {
+        for i in 0..boards.len() {
+            if solved_boards[i] {
+                continue;
+            }
+
+            let board = &mut boards[i];
+            let bingo = board.mark(n);
+
+            if bingo {
+                solved_boards[i] = true;
+                remainder -= 1;
+
+                if remainder == 0 {
+                    return Ok((board.sum_unmarked() * n).to_string());
+                }
+            }
+        }
+    }
+
+    Err(AocError::no_solution("not every board wins"))
+}
+
+const SIZE: usize = 5;
+
+struct Board {
+    numbers: [[u32; SIZE]; SIZE],
+}
+
+impl Board {
+    fn mark(&mut self, n: u32) -> bool {
+        for i in 0..SIZE {
+            for j in 0..SIZE {
+                if self.numbers[i][j] == n {
+                    self.numbers[i][j] = 0;
+                    return (0..SIZE).all(|i| { self.numbers[i][j] == 0 }) ||
+                        (0..SIZE).all(|j| { self.numbers[i][j] == 0 });
+                }
+            }
+        }
+
+        false
+    }
+
+    fn sum_unmarked(&self) -> u32 {
+        self.numbers.iter().flatten().sum()
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Vec<Board>, Vec<u32>)> {
+    let mut lines = lines(reader);
+    let numbers = parse_line(&lines.expect("drawn numbers")?, list(",", integer("number")))?;
+
+    let mut blocks = blocks(lines);
+    let mut boards = Vec::new();
+    while let Some(block) = blocks.next().transpose()? {
+        let rows = block.iter().map(parse_row).collect::<Result<Vec<_>>>()?;
+        match rows.try_into() {
+            Ok(numbers) => boards.push(Board { numbers }),
+            Err(rows) => return Err(AocError::parse(
+                block.last().map_or(blocks.number(), |line| line.number),
+                1,
+                format!("{} board rows", SIZE),
+                format!("{} rows", rows.len()),
+            )),
+        }
+    }
+
+    Ok((boards, numbers))
+}
+
+fn parse_row(line: &Line) -> Result<[u32; SIZE]> {
+    parse_line(line, words(integer("number")))?
+        .try_into()
+        .map_err(|_| line.error(&line.text, format!("row of {} numbers", SIZE)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "4512")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "1924")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("7,4,9\n\n22 13 17 11  0\n 8  2 23  4 24".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 1: expected 5 board rows, found 2 rows");
+
+        let err = part1(BufReader::new("7,4,9\n\n22 13 17 11".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: expected row of 5 numbers, found \"22 13 17 11\"");
+    }
+}
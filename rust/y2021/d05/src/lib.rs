@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+use std::io::BufRead;
+use std::ops::AddAssign;
+
+use aoc_core::{Example, Grid, Input, IntoInput, Result, Solution};
+use aoc_parse::pair;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Hydrothermal Venture"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("5"), Some("12")),
+        ]
+    }
+}
+
+pub fn part1<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let mut grid = Grid::new(SIZE, SIZE, 0);
+
+    parse_input(&input.into_input()?)?
+        .iter()
+        .filter(|line| no_diagonals(line))
+        .for_each(|line| { draw_line(line, &mut grid) });
+
+    Ok(count_overlaps(&grid).to_string())
+}
+
+pub fn part2<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let mut grid = Grid::new(SIZE, SIZE, 0);
+
+    parse_input(&input.into_input()?)?
+        .iter()
+        .for_each(|line| { draw_line(line, &mut grid) });
+
+    Ok(count_overlaps(&grid).to_string())
+}
+
+const SIZE: usize = 1000;
+
+#[derive(PartialEq, Clone, Copy)]
+struct Point(i16, i16);
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+    }
+}
+
+#[derive(Clone)]
+struct Line(Point, Point);
+
+fn parse_input(input: &Input) -> Result<Vec<Line>> {
+    let point = pair(coordinate, ",", coordinate);
+    let segment = pair(&point, " -> ", &point);
+    input.lines()
+        .map(|line| {
+            let ((x1, y1), (x2, y2)) = aoc_parse::parse_line(&line, &segment)?;
+            // Lines are only ever drawn one step at a time, along an axis or a diagonal.
+            if x1 != x2 && y1 != y2 && (x1 - x2).abs() != (y1 - y2).abs() {
+                return Err(line.error(&line.text, "horizontal, vertical or diagonal line"));
+            }
+            Ok(Line(Point(x1, y1), Point(x2, y2)))
+        })
+        .collect()
+}
+
+// Coordinates must fit on the grid.
+fn coordinate(line: &aoc_core::Line, s: &str) -> Result<i16> {
+    match line.integer::<i16>(s, "coordinate") {
+        Ok(n) if (0..SIZE as i16).contains(&n) => Ok(n),
+        _ => Err(line.error(s, format!("coordinate between 0 and {}", SIZE - 1))),
+    }
+}
+
+fn no_diagonals(line: &Line) -> bool {
+    line.0.0 == line.1.0 || line.0.1 == line.1.1
+}
+
+// Only whether lines overlap matters, so the counts saturate rather than overflow when more than
+// 255 lines cross a point.
+fn draw_line(line: &Line, grid: &mut Grid<u8>) {
+    let delta = calculate_delta(line);
+
+    let mut cur = line.0;
+
+    mark(grid, cur);
+
+    while cur != line.1 {
+        cur += delta;
+        mark(grid, cur);
+    }
+}
+
+fn mark(grid: &mut Grid<u8>, point: Point) {
+    let count = &mut grid[(point.0 as usize, point.1 as usize)];
+    *count = count.saturating_add(1);
+}
+
+fn calculate_delta(line: &Line) -> Point {
+    Point(
+        calculate_delta_coord(line.1.0.cmp(&line.0.0)),
+        calculate_delta_coord(line.1.1.cmp(&line.0.1)),
+    )
+}
+
+fn calculate_delta_coord(ordering: Ordering) -> i16 {
+    match ordering {
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+    }
+}
+
+fn count_overlaps(grid: &Grid<u8>) -> usize {
+    grid.iter()
+        .filter(|&&v| { v > 1u8 })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "5")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "12")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("0,9 -> 5,9\n8,0 => 0,8".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected \" -> \", found \"8,0 => 0,8\"");
+        let err = part1(BufReader::new("0,9 -> 5,1000".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 10: expected coordinate between 0 and 999, found \"1000\"");
+        let err = part2(BufReader::new("0,9 -> 5,9\n1,1 -> 3,5\n".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected horizontal, vertical or diagonal line, found \"1,1 -> 3,5\"");
+    }
+
+    #[test]
+    fn test_many_crossings() {
+        let input = "0,0 -> 0,1\n".repeat(300);
+        assert_eq!(part1(input.as_str()).unwrap(), "2");
+        assert_eq!(part2(input.as_str()).unwrap(), "2");
+    }
+}
@@ -0,0 +1,119 @@
+use std::io::BufRead;
+
+use aoc_core::{Example, lines, Result, Solution};
+
+const MAX_DAYS: usize = 256;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Lanternfish"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("5934"), Some("26984457539")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    solve_days(reader, 80)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    solve_days(reader, 256)
+}
+
+fn solve_days<R: BufRead>(reader: R, days: i32) -> Result<String> {
+    // A fish with a timer of 0 still has every one of the days left, so the memo holds 0 to
+    // MAX_DAYS days inclusive.
+    let mut memo = [0; MAX_DAYS + 1];
+    let line = lines(reader).expect("fish timers")?;
+    let offsets = line.text
+        .split(',')
+        .map(|n| line.parse::<u8>(n, "fish timer"))
+        .collect::<Result<Vec<u8>>>()?;
+    Ok(offsets
+        .into_iter()
+        .fold(0, |accum, offset| { accum + solve_single_fish(days - offset as i32, &mut memo) })
+        .to_string())
+}
+
+fn solve_single_fish(days: i32, memo: &mut [u64; MAX_DAYS + 1]) -> u64 {
+    if days <= 0 {
+        1
+    } else if memo[days as usize] != 0 {
+        memo[days as usize]
+    } else {
+        let mut countdown_days = days;
+        let mut result = 1;
+        while countdown_days > 0 {
+            result += solve_single_fish(countdown_days - 9, memo);
+            countdown_days -= 7;
+        }
+        memo[days as usize] = result;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "5934")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "26984457539")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("3,4,-3,1,2".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 5: expected fish timer, found \"-3\"");
+    }
+
+    // simulate counts the fish day by day, by how many there are with each timer.
+    fn simulate(timers: &[usize], days: usize) -> u64 {
+        let mut counts = [0u64; 9];
+        for &timer in timers {
+            counts[timer] += 1;
+        }
+        for _ in 0..days {
+            counts.rotate_left(1);
+            counts[6] += counts[8];
+        }
+        counts.iter().sum()
+    }
+
+    #[test]
+    fn test_timer_zero() {
+        assert_eq!(part1(BufReader::new("0,8".as_bytes())).unwrap(), simulate(&[0, 8], 80).to_string());
+        assert_eq!(part2(BufReader::new("0,8".as_bytes())).unwrap(), simulate(&[0, 8], 256).to_string());
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "The Treachery of Whales"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("37"), Some("168")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, get_total_linear_move_cost)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, get_total_triangular_move_cost)
+}
+
+fn solve<R: BufRead>(reader: R, cost_fn: impl Fn(&[i32], i32) -> Option<i64>) -> Result<String> {
+    Ok(descend_gradient(&parse_input(reader)?, cost_fn)?.to_string())
+}
+
+const MAX_POSITION: i32 = 1_000_000;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<i32>> {
+    let line = lines(reader).expect("crab positions")?;
+    line.text
+        .split(',')
+        .map(|n| match line.integer::<i32>(n, "crab position") {
+            Ok(position) if (0..=MAX_POSITION).contains(&position) => Ok(position),
+            _ => Err(line.error(n, format!("crab position between 0 and {}", MAX_POSITION))),
+        })
+        .collect()
+}
+
+// Positions are bounded, so only adding up the costs of many crabs can overflow.
+fn get_total_linear_move_cost(positions: &[i32], target_pos: i32) -> Option<i64> {
+    positions.iter().try_fold(0i64, |accum, pos| {
+        accum.checked_add((pos - target_pos).abs() as i64)
+    })
+}
+
+fn get_total_triangular_move_cost(positions: &[i32], target_pos: i32) -> Option<i64> {
+    positions.iter().try_fold(0i64, |accum, pos| {
+        let n = (pos - target_pos).abs() as i64;
+        accum.checked_add(n * (n + 1) / 2)
+    })
+}
+
+fn descend_gradient(v: &[i32], cost_fn: impl Fn(&[i32], i32) -> Option<i64>) -> Result<i64> {
+    let cost_at = |pos| cost_fn(v, pos).ok_or_else(|| AocError::invalid("total fuel cost does not fit in 64 bits"));
+    let mut start = 0;
+    let mut end = *v.iter().max().unwrap();
+    loop {
+        let search = start + (end - start) / 2;
+        let cost = cost_at(search)?;
+        let next_cost = cost_at(search + 1)?;
+        let last_cost = cost_at(search - 1)?;
+        if cost <= last_cost && cost <= next_cost {
+            return Ok(cost);
+        }
+        if last_cost < cost {
+            end = search;
+        } else {
+            start = search + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "37")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "168")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("16,1,2,,0".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 8: expected crab position between 0 and 1000000, found \"\"");
+        let err = part1(BufReader::new("16,-1,2".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 4: expected crab position between 0 and 1000000, found \"-1\"");
+        let err = part2(BufReader::new("0,2147483647".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 3: expected crab position between 0 and 1000000, found \"2147483647\"");
+    }
+
+    #[test]
+    fn test_far_apart() {
+        assert_eq!(part2(BufReader::new("0,1000000".as_bytes())).unwrap(), "250000500000");
+    }
+}
@@ -0,0 +1,203 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Line, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Seven Segment Search"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("26"), Some("61229")),
+            Example::new("single", include_str!("testdata/single.txt"), Some("0"), Some("5353")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, count_unique_segment_outputs)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, unscramble_output)
+}
+
+fn solve<R: BufRead>(reader: R, line_fn: impl Fn(&Line) -> Result<u32>) -> Result<String> {
+    let mut total = 0;
+    for line in lines(reader) {
+        total += line_fn(&line?)?;
+    }
+    Ok(total.to_string())
+}
+
+fn count_unique_segment_outputs(line: &Line) -> Result<u32> {
+    let (_, output) = parse_entry(line)?;
+    Ok(output
+        .into_iter()
+        .filter(|pattern| matches!(pattern.len(), 2 | 4 | 3 | 7))
+        .count() as u32)
+}
+
+fn unscramble_output(line: &Line) -> Result<u32> {
+    let (patterns, output) = parse_entry(line)?;
+    let scrambled_frequencies = patterns.into_iter().collect::<CompleteDistribution>();
+
+    output
+        .into_iter()
+        .try_fold(0u32, |acc, pattern| {
+            let n = solve_scrambled_pattern(pattern, &scrambled_frequencies)
+                .ok_or_else(|| AocError::no_solution(format!("cannot unscramble line {}", line.number)))?;
+            Ok(acc * 10 + n)
+        })
+}
+
+// parse_entry splits a line into its signal patterns and output value, checking that every
+// pattern only uses the segments a through g.
+fn parse_entry<'a>(line: &'a Line) -> Result<(Vec<&'a str>, Vec<&'a str>)> {
+    let (patterns, output) = line.split_once(&line.text, " | ")?;
+    let patterns = parse_patterns(line, patterns)?;
+    let output = parse_patterns(line, output)?;
+    Ok((patterns, output))
+}
+
+fn parse_patterns<'a>(line: &Line, s: &'a str) -> Result<Vec<&'a str>> {
+    s.split_whitespace()
+        .map(|pattern| {
+            if pattern.len() <= NUM_SEGMENTS && pattern.bytes().all(|b| (b'a'..=b'g').contains(&b)) {
+                Ok(pattern)
+            } else {
+                Err(line.error(pattern, "segments a through g"))
+            }
+        })
+        .collect()
+}
+
+const NUM_SEGMENTS: usize = 7;
+
+// How frequently a segment occurs, given each total lit segments. The index refers to the number of
+// lit segments, 0-indexed. NB: The first two indices are never utilized, and the last index is
+// always 1.
+#[derive(PartialEq, Default)]
+struct FrequencyDistribution([u8; NUM_SEGMENTS + 1]);
+
+impl FrequencyDistribution {
+    fn solve_char_index(&self) -> Option<usize> {
+        (0..NUM_SEGMENTS).find(|&i| *self == CompleteDistribution::WELL_KNOWN.0[i])
+    }
+}
+
+// All display segments, in terms of how frequently they occur for a number with a given number of
+// total lit segments.
+#[derive(Default)]
+struct CompleteDistribution([FrequencyDistribution; NUM_SEGMENTS]);
+
+impl<'a> FromIterator<&'a str> for CompleteDistribution {
+    fn from_iter<T: IntoIterator<Item=&'a str>>(iter: T) -> Self {
+        let mut output = CompleteDistribution::default();
+
+        iter.into_iter().for_each(|pattern| {
+            pattern.chars().for_each(|ch| {
+                output.0[char_to_index(ch)].0[pattern.len()] += 1
+            })
+        });
+
+        output
+    }
+}
+
+impl CompleteDistribution {
+    // The frequency distributions with unscrambled settings.
+    const WELL_KNOWN: CompleteDistribution = CompleteDistribution([
+        FrequencyDistribution([0, 0, 0, 1, 0, 3, 3, 1]), // a
+        FrequencyDistribution([0, 0, 0, 0, 1, 1, 3, 1]), // b
+        FrequencyDistribution([0, 0, 1, 1, 1, 2, 2, 1]), // c
+        FrequencyDistribution([0, 0, 0, 0, 1, 3, 2, 1]), // d
+        FrequencyDistribution([0, 0, 0, 0, 0, 1, 2, 1]), // e
+        FrequencyDistribution([0, 0, 1, 1, 1, 2, 3, 1]), // f
+        FrequencyDistribution([0, 0, 0, 0, 0, 3, 3, 1]), // g
+    ]);
+}
+
+fn char_to_index(ch: char) -> usize {
+    ((ch as u8) - b'a') as usize
+}
+
+fn solve_scrambled_pattern(pattern: &str, scrambled_frequencies: &CompleteDistribution) -> Option<u32> {
+    let mut display = 0u8;
+
+    for ch in pattern.chars() {
+        display |= 1 << (NUM_SEGMENTS - 1 - scrambled_frequencies.0[char_to_index(ch)].solve_char_index()?);
+    }
+
+    let digit = match display {
+        0b1110111 => 0,
+        0b0010010 => 1,
+        0b1011101 => 2,
+        0b1011011 => 3,
+        0b0111010 => 4,
+        0b1101011 => 5,
+        0b1101111 => 6,
+        0b1010010 => 7,
+        0b1111111 => 8,
+        0b1111011 => 9,
+        _ => return None,
+    };
+    Some(digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+    const SINGLE: &[u8] = include_str!("testdata/single.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "26")
+    }
+
+    #[test]
+    fn test_part1_single() {
+        assert_eq!(part1(BufReader::new(SINGLE)).unwrap(), "0")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "61229")
+    }
+
+    #[test]
+    fn test_part2_single() {
+        assert_eq!(part2(BufReader::new(SINGLE)).unwrap(), "5353")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("ab cd | ef\nab cd ef".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected \" | \", found \"ab cd ef\"");
+        let err = part2(BufReader::new("ab cx | ab".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 4: expected segments a through g, found \"cx\"");
+    }
+}
@@ -0,0 +1,112 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Grid, lines, Pos, Result, Solution};
+use aoc_parse::digit_grid;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Smoke Basin"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("15"), Some("1134")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let grid = digit_grid(lines(reader), "height")?;
+
+    Ok(grid.positions()
+        .filter(|&pos| grid.neighbours4(pos).all(|n| grid[n] > grid[pos]))
+        .map(|pos| grid[pos] as u32 + 1)
+        .sum::<u32>()
+        .to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let grid = digit_grid(lines(reader), "height")?;
+
+    let mut visited = Grid::new(grid.height(), grid.width(), false);
+
+    let mut basins = grid.positions()
+        .map(|pos| flood(&grid, &mut visited, pos))
+        .filter(|&v| v > 0)
+        .collect::<Vec<u32>>();
+
+    basins.sort();
+
+    if basins.len() < 3 {
+        return Err(AocError::no_solution("fewer than three basins"));
+    }
+
+    Ok(basins[basins.len() - 3..basins.len()]
+        .iter()
+        .product::<u32>()
+        .to_string())
+}
+
+fn flood(grid: &Grid<u8>, visited: &mut Grid<bool>, start: Pos) -> u32 {
+    let mut result = 0u32;
+
+    let mut queue: Vec<Pos> = vec![start];
+
+    while let Some(pos) = queue.pop() {
+        if visited[pos] || grid[pos] == 9 {
+            continue;
+        }
+
+        visited[pos] = true;
+        result += 1;
+
+        queue.extend(grid.neighbours4(pos));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "15")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "1134")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("123\n4x6".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: expected height, found \"x\"");
+        let err = part1(BufReader::new("123\n45".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected row of 3 columns, found \"45\"");
+    }
+}
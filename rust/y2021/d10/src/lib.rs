@@ -0,0 +1,170 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Line, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Syntax Scoring"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("26397"), Some("288957")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let mut stack = Vec::new();
+    let mut total = 0;
+
+    for line in lines(reader) {
+        if let Status::Corrupted(c) = autocomplete_line(&line?, &mut stack)? {
+            total += syntax_error_score(c);
+        }
+    }
+
+    Ok(total.to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let mut stack = Vec::new();
+    let mut scores = Vec::new();
+
+    for line in lines(reader) {
+        if let Status::Incomplete(remainder) = autocomplete_line(&line?, &mut stack)? {
+            scores.push(autocomplete_tool_score(remainder));
+        }
+    }
+
+    if scores.is_empty() {
+        return Err(AocError::no_solution("no incomplete lines"));
+    }
+
+    scores.sort();
+
+    Ok(scores[scores.len() / 2].to_string())
+}
+
+enum Status {
+    // The line is valid but incomplete, along with the autocompleted characters.
+    Incomplete(String),
+    // The line is invalid, along with the first invalid character.
+    Corrupted(char),
+}
+
+fn autocomplete_line(line: &Line, stack: &mut Vec<char>) -> Result<Status> {
+    stack.clear();
+
+    for (i, c) in line.text.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => {
+                stack.push(c);
+            }
+            ')' | ']' | '}' | '>' => {
+                match stack.pop() {
+                    Some(p) => {
+                        if p != invert(c) {
+                            return Ok(Status::Corrupted(c));
+                        }
+                    }
+                    None => {
+                        return Ok(Status::Corrupted(c));
+                    }
+                }
+            }
+            _ => {
+                return Err(line.error(&line.text[i..i + c.len_utf8()], "bracket"));
+            }
+        }
+    }
+
+    let mut result = String::new();
+
+    while let Some(c) = stack.pop() {
+        result.push(invert(c));
+    }
+
+    Ok(Status::Incomplete(result))
+}
+
+fn invert(c: char) -> char {
+    match c {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        ')' => '(',
+        ']' => '[',
+        '}' => '{',
+        '>' => '<',
+        _ => panic!("illegal char {}", c)
+    }
+}
+
+fn syntax_error_score(c: char) -> u32 {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("illegal char {}", c)
+    }
+}
+
+fn autocomplete_tool_score(remainder: String) -> u64 {
+    remainder
+        .chars()
+        .fold(0, |acc, c| {
+            acc * 5 + match c {
+                ')' => 1,
+                ']' => 2,
+                '}' => 3,
+                '>' => 4,
+                _ => panic!("illegal char {}", c)
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "26397")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "288957")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("[]\n(<x>)".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 3: expected bracket, found \"x\"");
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::BufRead;
+
+use aoc_core::{Example, Grid, lines, Result, Solution};
+use aoc_parse::digit_grid;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Dumbo Octopus"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("1656"), Some("195")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let mut state = parse_input(reader)?;
+    let mut total_flashes = 0;
+
+    for _ in 0..100 {
+        total_flashes += state.tick();
+    }
+
+    Ok(total_flashes.to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let mut state = parse_input(reader)?;
+    let mut i = 0;
+    while !state.all_zero() {
+        state.tick();
+        i += 1;
+    }
+    Ok(i.to_string())
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<State> {
+    Ok(State(digit_grid(lines(reader), "energy level")?))
+}
+
+struct State(Grid<u8>);
+
+impl State {
+    fn tick(&mut self) -> u32 {
+        self.0.iter_mut().for_each(|energy| *energy += 1);
+
+        let mut flashes = 0u32;
+
+        let mut flashing = true;
+
+        while flashing {
+            flashing = false;
+
+            for pos in self.0.positions() {
+                if self.0[pos] < 10 {
+                    continue;
+                }
+
+                self.0[pos] = 0;
+                flashes += 1;
+                flashing = true;
+
+                for neighbour in self.0.neighbours8(pos) {
+                    if self.0[neighbour] == 0 {
+                        continue;
+                    }
+
+                    self.0[neighbour] += 1;
+                }
+            }
+        }
+
+        flashes
+    }
+
+    fn all_zero(&self) -> bool {
+        self.0.iter().all(|&energy| energy == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "1656")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "195")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("5483143223\n274585471".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected row of 10 columns, found \"274585471\"");
+        let err = part1(BufReader::new("54831432x3".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 9: expected energy level, found \"x\"");
+    }
+}
@@ -1,31 +1,57 @@
-#![feature(test)]
-
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+
+use aoc_core::{Example, Line, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Passage Pathing"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
 
-fn main() {
-    println!("{}", part1(read_input()));
-    println!("{}", part2(read_input()));
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("small", include_str!("testdata/small.txt"), Some("10"), Some("36")),
+            Example::new("medium", include_str!("testdata/medium.txt"), Some("19"), Some("103")),
+            Example::new("large", include_str!("testdata/large.txt"), Some("226"), Some("3509")),
+        ]
+    }
 }
 
-fn part1<R: BufRead>(reader: R) -> String {
-    let graph = Graph::from(reader);
-    count_paths_no_revisit_small_caves(
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let graph = Graph::parse(reader)?;
+    Ok(count_paths_no_revisit_small_caves(
         &graph,
         graph.start,
         &vec![false; graph.adjacency_list.len()],
-    ).to_string()
+    ).to_string())
 }
 
-fn part2<R: BufRead>(reader: R) -> String {
-    let graph = Graph::from(reader);
-    count_paths_single_revisit_small_caves(
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let graph = Graph::parse(reader)?;
+    Ok(count_paths_single_revisit_small_caves(
         &graph,
         graph.start,
         &vec![false; graph.adjacency_list.len()],
         usize::MAX,
-    ).to_string()
+    ).to_string())
 }
 
 fn count_paths_no_revisit_small_caves(graph: &Graph, cur: usize, visited_small: &[bool]) -> u32 {
@@ -87,8 +113,8 @@ struct Graph {
     end: usize,
 }
 
-impl<R: BufRead> From<R> for Graph {
-    fn from(reader: R) -> Self {
+impl Graph {
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
         let mut index_lookup: HashMap<String, usize> = HashMap::new();
         let mut adjacency_list = Vec::new();
         let mut smalls = Vec::new();
@@ -99,7 +125,7 @@ impl<R: BufRead> From<R> for Graph {
                     let index = adjacency_list.len();
                     index_lookup.insert(String::from(name), index);
                     adjacency_list.push(Vec::new());
-                    smalls.push((name.chars().next().unwrap() as u8) >= ('a' as u8));
+                    smalls.push((name.chars().next().unwrap() as u8) >= b'a');
                     index
                 }
                 Some(v) => *v,
@@ -113,11 +139,12 @@ impl<R: BufRead> From<R> for Graph {
             adjacency_list[b_index].push(a_index);
         };
 
-        for line in reader.lines().map(Result::unwrap) {
-            let mut split = line.split("-");
+        for line in lines(reader) {
+            let line = line?;
+            let (a, b) = line.split_once(&line.text, "-")?;
             add(
-                split.next().unwrap(),
-                split.next().unwrap(),
+                parse_cave(&line, a)?,
+                parse_cave(&line, b)?,
                 &mut adjacency_list,
             );
         }
@@ -125,25 +152,26 @@ impl<R: BufRead> From<R> for Graph {
         let start = name_to_index("start", &mut adjacency_list);
         let end = name_to_index("end", &mut adjacency_list);
 
-        Graph {
+        Ok(Graph {
             adjacency_list,
             smalls,
             start,
             end,
-        }
+        })
     }
 }
 
-fn read_input() -> BufReader<File> {
-    BufReader::new(File::open("input.txt").unwrap())
+fn parse_cave<'a>(line: &Line, name: &'a str) -> Result<&'a str> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(name)
+    } else {
+        Err(line.error(name, "cave name"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
-    use std::fs;
-    use test::Bencher;
+    use std::io::BufReader;
 
     use super::*;
 
@@ -153,45 +181,39 @@ mod tests {
 
     #[test]
     fn test_part1_small() {
-        assert_eq!(part1(BufReader::new(SMALL)), "10")
+        assert_eq!(part1(BufReader::new(SMALL)).unwrap(), "10")
     }
 
     #[test]
     fn test_part1_medium() {
-        assert_eq!(part1(BufReader::new(MEDIUM)), "19")
+        assert_eq!(part1(BufReader::new(MEDIUM)).unwrap(), "19")
     }
 
     #[test]
     fn test_part1_large() {
-        assert_eq!(part1(BufReader::new(LARGE)), "226")
+        assert_eq!(part1(BufReader::new(LARGE)).unwrap(), "226")
     }
 
     #[test]
     fn test_part2_small() {
-        assert_eq!(part2(BufReader::new(SMALL)), "36")
+        assert_eq!(part2(BufReader::new(SMALL)).unwrap(), "36")
     }
 
     #[test]
     fn test_part2_medium() {
-        assert_eq!(part2(BufReader::new(MEDIUM)), "103")
+        assert_eq!(part2(BufReader::new(MEDIUM)).unwrap(), "103")
     }
 
     #[test]
     fn test_part2_large() {
-        assert_eq!(part2(BufReader::new(LARGE)), "3509")
+        assert_eq!(part2(BufReader::new(LARGE)).unwrap(), "3509")
     }
 
-    #[bench]
-    fn bench_part1(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        let input = input.as_bytes();
-        b.iter(|| part1(BufReader::new(input)))
-    }
-
-    #[bench]
-    fn bench_part2(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        let input = input.as_bytes();
-        b.iter(|| part2(BufReader::new(input)))
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("start-A\nA end".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected \"-\", found \"A end\"");
+        let err = part1(BufReader::new("start-".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 7: expected cave name, found end of line");
     }
 }
@@ -0,0 +1,161 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Bounds, Example, lines, Result, Solution, SparseGrid};
+use aoc_parse::{blocks, integer, one_of, pair, parse_line, prefixed};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Transparent Origami"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("17"), Some("#####\n#   #\n#   #\n#   #\n#####\n")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let (mut paper, instructions) = parse_input(reader)?;
+    paper.fold(instructions[0]);
+    Ok(paper.count_dots().to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let (mut paper, instructions) = parse_input(reader)?;
+    for instruction in instructions {
+        paper.fold(instruction);
+    }
+    Ok(String::from(&paper))
+}
+
+#[derive(Copy, Clone)]
+enum Instruction {
+    X(i64),
+    Y(i64),
+}
+
+// Paper holds the dots, by (y, x) so that it renders with y going down the page.
+struct Paper(SparseGrid<bool>);
+
+impl Paper {
+    fn count_dots(&self) -> usize {
+        self.0.len()
+    }
+
+    // fold folds the paper up or left along a line. Dots that land on each other merge.
+    fn fold(&mut self, instruction: Instruction) {
+        let mut folded = SparseGrid::new(false);
+        for ((y, x), _) in self.0.cells() {
+            let dot = match instruction {
+                Instruction::X(fold_x) if x > fold_x => (y, fold_x - (x - fold_x)),
+                Instruction::Y(fold_y) if y > fold_y => (fold_y - (y - fold_y), x),
+                _ => (y, x),
+            };
+            folded.set(dot, true);
+        }
+        self.0 = folded;
+    }
+}
+
+// The paper is drawn from its top left corner, even if the dots don't reach it.
+impl From<&Paper> for String {
+    fn from(paper: &Paper) -> Self {
+        match paper.0.bounds() {
+            Some(bounds) => {
+                let window = Bounds { top: bounds.top.min(0), left: bounds.left.min(0), ..bounds };
+                paper.0.render(window, |&dot| if dot { '#' } else { ' ' })
+            }
+            None => String::new(),
+        }
+    }
+}
+
+type Axis = fn(i64) -> Instruction;
+
+const AXES: &[(&str, Axis)] = &[("x", Instruction::X), ("y", Instruction::Y)];
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Paper, Vec<Instruction>)> {
+    let mut blocks = blocks(lines(reader));
+
+    let Some(dots) = blocks.next().transpose()? else {
+        return Err(AocError::end_of_input(blocks.number() + 1, "dot"));
+    };
+    let dot = pair(integer::<u16>("x coordinate"), ",", integer::<u16>("y coordinate"));
+    let mut paper = Paper(SparseGrid::new(false));
+    for line in &dots {
+        let (x, y) = parse_line(line, &dot)?;
+        paper.0.set((y as i64, x as i64), true);
+    }
+
+    let Some(instructions) = blocks.next().transpose()? else {
+        return Err(AocError::end_of_input(blocks.number() + 1, "fold instruction"));
+    };
+    let instruction = prefixed("fold along ", pair(
+        one_of(AXES, "axis x or y"),
+        "=",
+        integer::<u16>("fold position"),
+    ));
+    let instructions = instructions.iter()
+        .map(|line| parse_line(line, &instruction).map(|(axis, value)| axis(value as i64)))
+        .collect::<Result<_>>()?;
+
+    if let Some(extra) = blocks.next().transpose()? {
+        return Err(extra[0].error(&extra[0].text, "end of input"));
+    }
+    Ok((paper, instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "17")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "#####
+#   #
+#   #
+#   #
+#####
+")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("6,10\n0,14\n\nfold along z=7".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 12: expected axis x or y, found \"z\"");
+        let err = part1(BufReader::new("6,10\n0;14".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected \",\", found \"0;14\"");
+        let err = part1(BufReader::new("6,10\n\n".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: expected fold instruction, found end of input");
+        let err = part1(BufReader::new("6,10\n\nfold along y=7\n\n0,1".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 5, column 1: expected end of input, found \"0,1\"");
+    }
+}
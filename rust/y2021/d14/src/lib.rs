@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::ops::{Add, AddAssign, Index, IndexMut};
+
+use aoc_core::{AocError, Example, Line, lines, Result, Solution};
+use aoc_parse::{chars, pair, parse_line, Parser};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Extended Polymerization"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("1588"), Some("2188189693529")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, 10)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, 40)
+}
+
+fn solve<R: BufRead>(reader: R, steps: u8) -> Result<String> {
+    let element_count = count_elements_after_steps(reader, steps)?;
+    Ok(subtract_most_from_least_common_element(element_count).to_string())
+}
+
+fn count_elements_after_steps<R: BufRead>(reader: R, steps: u8) -> Result<ElementCount> {
+    let (template, rules) = parse_input(reader)?;
+
+    let mut memo: HashMap<MemoKey, ElementCount> = HashMap::new();
+    let mut element_count = ElementCount::default();
+
+    for i in 0..template.len() - 1 {
+        let pair = [template[i], template[i + 1]];
+        element_count += count_elements(pair, steps, &rules, &mut memo)?;
+    }
+
+    // Need to add the last template character manually since it was not counted.
+    element_count[template[template.len() - 1]] += 1;
+
+    Ok(element_count)
+}
+
+fn subtract_most_from_least_common_element(element_count: ElementCount) -> u64 {
+    let mut least = 0;
+    let mut most = 0;
+
+    for i in 0..NUM_LETTERS {
+        if element_count[i] > element_count[most] {
+            most = i;
+        }
+
+        if element_count[least] == 0 || (element_count[i] != 0 && element_count[i] < element_count[least]) {
+            least = i;
+        }
+    }
+
+    element_count[most] - element_count[least]
+}
+
+fn count_elements(pair: [char; 2], steps: u8, rules: &Rules, memo: &mut HashMap<MemoKey, ElementCount>) -> Result<ElementCount> {
+    let steps = steps - 1;
+    let memo_key = MemoKey { steps, pair };
+
+    if let Some(v) = memo.get(&memo_key) {
+        return Ok(*v);
+    }
+
+    let inserted_element = *rules.get(&pair)
+        .ok_or_else(|| AocError::no_solution(format!("no insertion rule for {}{}", pair[0], pair[1])))?;
+
+    if steps == 0 {
+        let mut element_count = ElementCount::default();
+
+        element_count[pair[0]] += 1;
+        element_count[inserted_element] += 1;
+
+        Ok(element_count)
+    } else {
+        let left_pair = [pair[0], inserted_element];
+        let right_pair = [inserted_element, pair[1]];
+
+        let element_count = count_elements(left_pair, steps, rules, memo)? +
+            count_elements(right_pair, steps, rules, memo)?;
+
+        memo.insert(memo_key, element_count);
+
+        Ok(element_count)
+    }
+}
+
+type Rules = HashMap<[char; 2], char>;
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+struct MemoKey {
+    steps: u8,
+    pair: [char; 2],
+}
+
+const NUM_LETTERS: usize = 26;
+
+#[derive(Default, Copy, Clone)]
+struct ElementCount([u64; NUM_LETTERS]);
+
+impl AddAssign for ElementCount {
+    fn add_assign(&mut self, rhs: Self) {
+        for i in 0..NUM_LETTERS {
+            self[i] += rhs[i];
+        }
+    }
+}
+
+impl Add for ElementCount {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut output = ElementCount::default();
+        for i in 0..NUM_LETTERS {
+            output[i] = self[i] + rhs[i];
+        }
+        output
+    }
+}
+
+impl Index<usize> for ElementCount {
+    type Output = u64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<usize> for ElementCount {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl Index<char> for ElementCount {
+    type Output = u64;
+
+    fn index(&self, index: char) -> &Self::Output {
+        &self[(index as u8 - b'A') as usize]
+    }
+}
+
+impl IndexMut<char> for ElementCount {
+    fn index_mut(&mut self, index: char) -> &mut Self::Output {
+        &mut self[(index as u8 - b'A') as usize]
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Vec<char>, Rules)> {
+    let mut lines = lines(reader);
+
+    let line = lines.expect("polymer template")?;
+    let template = parse_line(&line, elements())?;
+    if template.is_empty() {
+        return Err(line.error(&line.text, "polymer template"));
+    }
+
+    let line = lines.expect("blank line")?;
+    if !line.is_empty() {
+        return Err(line.error(&line.text, "blank line"));
+    }
+
+    let rule = pair(exactly(elements(), "pair of elements"), " -> ", exactly(elements(), "element"));
+    let mut rules = HashMap::new();
+    for line in lines {
+        let ([a, b], [c]) = parse_line(&line?, &rule)?;
+        rules.insert([a, b], c);
+    }
+
+    Ok((template, rules))
+}
+
+fn elements() -> impl Parser<Vec<char>> {
+    chars("element", |c| c.is_ascii_uppercase().then_some(c))
+}
+
+// exactly requires parser to parse exactly N elements.
+fn exactly<const N: usize>(parser: impl Parser<Vec<char>>, expected: &'static str) -> impl Parser<[char; N]> {
+    move |line: &Line, s: &str| parser(line, s)?.try_into().map_err(|_| line.error(s, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "1588")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "2188189693529")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("NNCB\n\nCH -> b".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 7: expected element, found \"b\"");
+        let err = part1(BufReader::new("NNCB\n\nCHB -> B".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: expected pair of elements, found \"CHB\"");
+        let err = part1(BufReader::new("NNCB\n\nNN -> C".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "no solution: no insertion rule for NC");
+    }
+}
@@ -0,0 +1,98 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Grid, lines, Pos, Result, Solution};
+use aoc_parse::digit_grid;
+use pathfinding::directed::astar::astar;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Chiton"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("40"), Some("315")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, 1)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, 5)
+}
+
+fn solve<R: BufRead>(reader: R, factor: usize) -> Result<String> {
+    let tile = digit_grid(lines(reader), "risk level")?;
+
+    // The full cavern repeats the tile, adding one to each risk level for each tile right or down,
+    // and wrapping risk levels above 9 back around to 1.
+    let mut cavern = Grid::new(tile.height() * factor, tile.width() * factor, 0);
+    for (i, j) in cavern.positions() {
+        let risk = tile[(i % tile.height(), j % tile.width())];
+        let increase = (i / tile.height() + j / tile.width()) as u8;
+        cavern[(i, j)] = if increase == 0 { risk } else { (risk + increase - 1) % 9 + 1 };
+    }
+
+    let start = (0, 0);
+    let goal = (cavern.height() - 1, cavern.width() - 1);
+
+    let successors = |&p: &Pos| -> Vec<(Pos, u32)> {
+        cavern.neighbours4(p).map(|n| (n, cavern[n] as u32)).collect()
+    };
+
+    let heuristic = |p: &Pos| (goal.0.abs_diff(p.0) + goal.1.abs_diff(p.1)) as u32;
+
+    let success = |p: &Pos| *p == goal;
+
+    let (_, risk) = astar(&start, successors, heuristic, success)
+        .ok_or_else(|| AocError::no_solution("no path through the cavern"))?;
+    Ok(risk.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "40")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "315")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("116\n1-3".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: expected risk level, found \"-\"");
+        let err = part1(BufReader::new("".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 1: expected risk level, found end of input");
+    }
+}
@@ -0,0 +1,328 @@
+use std::ops::Range;
+
+use aoc_bits::{BitReader, BitWriter, Source};
+
+use crate::error::{DecodeError, EncodeError};
+use crate::packet::{Length, LengthType, Literal, Operator, PacketHeader, PacketType, ReadFrom, WriteTo};
+
+// MAX_DEPTH is how deeply packets may be nested, counting the outermost one. It keeps decoding
+// and evaluating, which recurse through the sub-packets, from running out of stack.
+pub const MAX_DEPTH: usize = 256;
+
+// Packet is a whole packet along with its sub-packets. Its span is the bits it takes up in the
+// transmission; packets built by hand are laid out as if the outermost one starts at bit 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packet {
+    pub version: u32,
+    pub span: Range<u64>,
+    pub body: Body,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Body {
+    Literal(Literal),
+    // The length type says how the sub-packets are counted: by the number of bits they take up,
+    // or by the number of them.
+    Operator {
+        operator: Operator,
+        length_type: LengthType,
+        children: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    pub fn literal(version: u32, value: u64) -> Self {
+        Packet::with_literal(version, Literal::from(value))
+    }
+
+    pub fn with_literal(version: u32, literal: Literal) -> Self {
+        let mut packet = Packet { version, span: 0..0, body: Body::Literal(literal) };
+        packet.place(0);
+        packet
+    }
+
+    // operator panics if the operator cannot take that many sub-packets.
+    pub fn operator(version: u32, operator: Operator, length_type: LengthType, children: Vec<Packet>) -> Self {
+        let arity = operator.arity();
+        assert!(arity.accepts(children.len()), "{} takes {} sub-packets, not {}", operator, arity, children.len());
+        let mut packet = Packet { version, span: 0..0, body: Body::Operator { operator, length_type, children } };
+        packet.place(0);
+        packet
+    }
+
+    // place sets the spans of the packet and its sub-packets as if it were encoded at offset.
+    fn place(&mut self, offset: u64) {
+        self.span = offset..offset + self.bits();
+        if let Body::Operator { length_type, children, .. } = &mut self.body {
+            let mut offset = offset + 7 + length_type.width() as u64;
+            for child in children {
+                child.place(offset);
+                offset = child.span.end;
+            }
+        }
+    }
+
+    pub fn packet_type(&self) -> PacketType {
+        match self.body {
+            Body::Literal(_) => PacketType::Literal,
+            Body::Operator { operator, .. } => PacketType::Operator(operator),
+        }
+    }
+
+    // bits is the number of bits the packet is encoded in. This is the length of the span unless
+    // the packet was decoded from a transmission that padded literals with leading zero groups.
+    pub fn bits(&self) -> u64 {
+        6 + match &self.body {
+            Body::Literal(literal) => 5 * literal.groups() as u64,
+            Body::Operator { length_type, children, .. } => {
+                1 + length_type.width() as u64 + children.iter().map(Packet::bits).sum::<u64>()
+            }
+        }
+    }
+
+    // decode reads a whole transmission in hex. Anything after the outermost packet must be the
+    // zero bits that pad it out to a whole number of bytes.
+    pub fn decode(hex: &[u8]) -> Result<Packet, DecodeError> {
+        let hex = hex.trim_ascii_end();
+        if hex.len() % 2 == 1 {
+            return Err(DecodeError::OddLength { digits: hex.len() });
+        }
+        let mut reader = BitReader::hex(hex);
+        let packet = Packet::read_from(&mut reader)?;
+        while !reader.at_end().map_err(|e| DecodeError::from_bits(e, "padding"))? {
+            let offset = reader.position();
+            if reader.read(1).map_err(|e| DecodeError::from_bits(e, "padding"))? == 1 {
+                return Err(DecodeError::Padding { offset });
+            }
+        }
+        Ok(packet)
+    }
+
+    // encode returns the packet as a BITS transmission in hex, padded with zero bits to a whole
+    // number of bytes. It fails if a field does not fit, such as a version above 7 or more
+    // sub-packets than the length can count.
+    pub fn encode(&self) -> Result<String, EncodeError> {
+        self.check_fields(0)?;
+        let mut writer = BitWriter::new();
+        self.write_to(&mut writer);
+        Ok(writer.into_hex())
+    }
+
+    // check_fields checks that every field of the packet, which starts at offset, fits in its bits.
+    fn check_fields(&self, offset: u64) -> Result<(), EncodeError> {
+        let fits = |field, value, width| {
+            if value < 1u64 << width { Ok(()) } else { Err(EncodeError { offset, field, value, width }) }
+        };
+        fits("version", self.version as u64, 3)?;
+        if let Body::Operator { length_type, children, .. } = &self.body {
+            fits(length_type.field(), length(*length_type, children), length_type.width())?;
+            let mut offset = offset + 7 + length_type.width() as u64;
+            for child in children {
+                child.check_fields(offset)?;
+                offset += child.bits();
+            }
+        }
+        Ok(())
+    }
+}
+
+// length is the length of an operator packet's sub-packets, counted the way length_type says.
+fn length(length_type: LengthType, children: &[Packet]) -> u64 {
+    match length_type {
+        LengthType::Bits => children.iter().map(Packet::bits).sum(),
+        LengthType::Packets => children.len() as u64,
+    }
+}
+
+impl<S: Source> ReadFrom<S> for Packet {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        Packet::read_nested(reader, 1)
+    }
+}
+
+impl Packet {
+    // read_nested reads a packet at the given depth, where the outermost packet is at depth 1.
+    fn read_nested<S: Source>(reader: &mut BitReader<S>, depth: usize) -> Result<Self, DecodeError> {
+        let start = reader.position();
+        if depth > MAX_DEPTH {
+            return Err(DecodeError::TooDeep { offset: start, max: MAX_DEPTH });
+        }
+        let PacketHeader { version, packet_type } = PacketHeader::read_from(reader)?;
+
+        let body = match packet_type {
+            PacketType::Literal => Body::Literal(Literal::read_from(reader)?),
+            PacketType::Operator(operator) => {
+                let Length { length_type, length } = Length::read_from(reader)?;
+                let children = match length_type {
+                    LengthType::Packets => {
+                        (0..length).map(|_| Packet::read_nested(reader, depth + 1)).collect::<Result<Vec<_>, _>>()?
+                    }
+                    LengthType::Bits => {
+                        let tail = reader.position() + length;
+                        let mut children = Vec::new();
+                        while reader.position() < tail {
+                            let offset = reader.position();
+                            children.push(Packet::read_nested(reader, depth + 1)?);
+                            if reader.position() > tail {
+                                return Err(DecodeError::Overrun { offset, end: tail });
+                            }
+                        }
+                        children
+                    }
+                };
+                if !operator.arity().accepts(children.len()) {
+                    return Err(DecodeError::Arity { offset: start, operator, count: children.len() });
+                }
+                Body::Operator { operator, length_type, children }
+            }
+        };
+
+        Ok(Packet { version: version.into(), span: start..reader.position(), body })
+    }
+}
+
+impl WriteTo for Packet {
+    fn write_to(&self, writer: &mut BitWriter) {
+        PacketHeader { version: self.version.into(), packet_type: self.packet_type() }.write_to(writer);
+        match &self.body {
+            Body::Literal(literal) => literal.write_to(writer),
+            Body::Operator { length_type, children, .. } => {
+                Length { length_type: *length_type, length: length(*length_type, children) }.write_to(writer);
+                children.iter().for_each(|child| child.write_to(writer));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_literal() {
+        assert_eq!(Packet::literal(6, 2021).encode().unwrap(), "D2FE28");
+        assert_eq!(Packet::literal(0, 0).bits(), 11);
+        assert_eq!(Packet::literal(0, 15).bits(), 11);
+        assert_eq!(Packet::literal(0, 16).bits(), 16);
+        assert_eq!(Packet::literal(0, u64::MAX).bits(), 6 + 5 * 16);
+    }
+
+    #[test]
+    fn test_encode_operators() {
+        let packet = Packet::operator(1, Operator::LessThan, LengthType::Bits, vec![
+            Packet::literal(6, 10),
+            Packet::literal(2, 20),
+        ]);
+        assert_eq!(packet.bits(), 49);
+        assert_eq!(packet.encode().unwrap(), "38006F45291200");
+
+        let packet = Packet::operator(7, Operator::Maximum, LengthType::Packets, vec![
+            Packet::literal(2, 1),
+            Packet::literal(4, 2),
+            Packet::literal(1, 3),
+        ]);
+        assert_eq!(packet.encode().unwrap(), "EE00D40C823060");
+    }
+
+    #[test]
+    fn test_decode() {
+        let packet = Packet::decode(b"38006F45291200\n").unwrap();
+        assert_eq!(packet, Packet::operator(1, Operator::LessThan, LengthType::Bits, vec![
+            Packet::literal(6, 10),
+            Packet::literal(2, 20),
+        ]));
+        let Body::Operator { children, .. } = &packet.body else { panic!("{:?}", packet) };
+        assert_eq!(packet.span, 0..49);
+        assert_eq!(children[0].span, 22..33);
+        assert_eq!(children[1].span, 33..49);
+    }
+
+    fn decode_error(hex: &str) -> String {
+        Packet::decode(hex.as_bytes()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(decode_error(""), "bit 0: transmission ends in the version");
+        assert_eq!(decode_error("D2FE"), "bit 16: transmission ends in the literal");
+        assert_eq!(decode_error("D2FG28"), "bit 12: expected hex digit, found 'G'");
+        assert_eq!(decode_error("D2FE2"), "transmission has an odd number of hex digits (5)");
+        assert_eq!(decode_error("D2FE29"), "bit 23: expected zero padding after the packet, found 1");
+        assert_eq!(decode_error("C0"), "bit 7: transmission ends in the length of the sub-packets");
+        assert_eq!(Packet::decode(b"D2FE2800").unwrap(), Packet::literal(6, 2021));
+    }
+
+    // write_operator writes the header and length of an operator packet without its sub-packets.
+    fn write_operator(writer: &mut BitWriter, operator: Operator, length_type: LengthType, length: u64) {
+        PacketHeader { version: 0.into(), packet_type: PacketType::Operator(operator) }.write_to(writer);
+        Length { length_type, length }.write_to(writer);
+    }
+
+    #[test]
+    fn test_decode_overrun() {
+        let mut writer = BitWriter::new();
+        write_operator(&mut writer, Operator::Sum, LengthType::Bits, 5);
+        Packet::literal(0, 1).write_to(&mut writer);
+        assert_eq!(
+            decode_error(&writer.into_hex()),
+            "bit 22: sub-packet runs past the end of its parent's sub-packets at bit 27",
+        );
+    }
+
+    #[test]
+    fn test_decode_arity() {
+        let mut writer = BitWriter::new();
+        write_operator(&mut writer, Operator::Minimum, LengthType::Packets, 0);
+        assert_eq!(decode_error(&writer.into_hex()), "bit 0: minimum packet has 0 sub-packets, expected at least 1");
+
+        let mut writer = BitWriter::new();
+        write_operator(&mut writer, Operator::Sum, LengthType::Packets, 1);
+        write_operator(&mut writer, Operator::GreaterThan, LengthType::Bits, 11);
+        Packet::literal(0, 1).write_to(&mut writer);
+        assert_eq!(decode_error(&writer.into_hex()), "bit 18: greater than packet has 1 sub-packets, expected 2");
+    }
+
+    #[test]
+    #[should_panic(expected = "equal to takes 2 sub-packets, not 3")]
+    fn test_operator_bad_arity() {
+        Packet::operator(0, Operator::EqualTo, LengthType::Packets, vec![Packet::literal(0, 1); 3]);
+    }
+
+    fn encode_error(packet: Packet) -> String {
+        packet.encode().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        assert_eq!(encode_error(Packet::literal(8, 0)), "bit 0: version 8 does not fit in 3 bits");
+
+        let children = vec![Packet::literal(0, 1); 3000];
+        let packet = Packet::operator(0, Operator::Sum, LengthType::Packets, children.clone());
+        assert_eq!(encode_error(packet), "bit 0: number of sub-packets 3000 does not fit in 11 bits");
+        let packet = Packet::operator(0, Operator::Sum, LengthType::Bits, children);
+        assert_eq!(encode_error(packet), "bit 0: length of the sub-packets 33000 does not fit in 15 bits");
+
+        let packet = Packet::operator(0, Operator::Sum, LengthType::Packets, vec![
+            Packet::literal(1, 1),
+            Packet::literal(9, 2),
+        ]);
+        assert_eq!(encode_error(packet), "bit 29: version 9 does not fit in 3 bits");
+    }
+
+    // nested wraps a literal in sum packets until it is depth packets deep.
+    fn nested(depth: usize) -> Packet {
+        (1..depth).fold(Packet::literal(0, 1), |packet, _| {
+            Packet::operator(0, Operator::Sum, LengthType::Packets, vec![packet])
+        })
+    }
+
+    #[test]
+    fn test_decode_too_deep() {
+        let packet = nested(MAX_DEPTH);
+        assert_eq!(Packet::decode(packet.encode().unwrap().as_bytes()).unwrap(), packet);
+
+        // Each sum packet takes 18 bits, so the packet past the limit starts at bit 256 * 18.
+        let hex = nested(MAX_DEPTH + 1).encode().unwrap();
+        assert_eq!(decode_error(&hex), "bit 4608: packet is nested more than 256 deep");
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt::Write as _;
+use std::io;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+use num_bigint::BigUint;
+
+use crate::ast::Packet;
+use crate::error::EvalError;
+use crate::eval::{depth, evaluate, packet_count, version_sum};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    // One JSON object per line, so that records can be read as they are written.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {}", s)),
+        }
+    }
+}
+
+// Stats describes a decoded transmission. The depth of a lone literal is 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub version_sum: u32,
+    pub value: BigUint,
+    pub packets: usize,
+    pub depth: usize,
+}
+
+impl Stats {
+    // of fails only for packets built by hand with the wrong number of sub-packets or nested too
+    // deeply, since big integers do not overflow. Evaluating first checks the depth before the
+    // other passes recurse through the packet.
+    pub fn of(packet: &Packet) -> Result<Self, EvalError> {
+        let value = evaluate(packet)?;
+        Ok(Stats {
+            version_sum: version_sum(packet),
+            value,
+            packets: packet_count(packet),
+            depth: depth(packet),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub transmissions: usize,
+    pub failed: usize,
+}
+
+// batch decodes a transmission per line of reader and writes a record for each to writer as it
+// goes. A line that does not decode gets a record with the error in it rather than stopping the
+// batch; blank lines are skipped. Only failing to read or write is an error.
+pub fn batch<R: BufRead, W: Write>(reader: R, mut writer: W, format: Format) -> io::Result<Summary> {
+    let mut summary = Summary::default();
+    if format == Format::Csv {
+        writeln!(writer, "line,version_sum,value,packets,depth,error")?;
+    }
+
+    for (i, line) in reader.split(b'\n').enumerate() {
+        let line = line?;
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+        let result = Packet::decode(&line)
+            .map_err(|e| e.to_string())
+            .and_then(|packet| Stats::of(&packet).map_err(|e| e.to_string()));
+        summary.transmissions += 1;
+        summary.failed += result.is_err() as usize;
+        writeln!(writer, "{}", record(i + 1, &result, format))?;
+    }
+
+    writer.flush()?;
+    Ok(summary)
+}
+
+fn record(line: usize, result: &Result<Stats, String>, format: Format) -> String {
+    match (format, result) {
+        (Format::Csv, Ok(stats)) => {
+            format!("{},{},{},{},{},", line, stats.version_sum, stats.value, stats.packets, stats.depth)
+        }
+        (Format::Csv, Err(e)) => format!("{},,,,,{}", line, csv_field(e)),
+        (Format::Json, Ok(stats)) => format!(
+            r#"{{"line":{},"version_sum":{},"value":{},"packets":{},"depth":{}}}"#,
+            line, stats.version_sum, stats.value, stats.packets, stats.depth,
+        ),
+        (Format::Json, Err(e)) => format!(r#"{{"line":{},"error":{}}}"#, line, json_string(e)),
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut output = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::MAX_DEPTH;
+    use crate::packet::{LengthType, Operator};
+
+    use super::*;
+
+    const LOG: &str = "8A004A801A8002F478\n\nC200B40A82\r\nD2FE2\n9C0141080250320F1802104A08\n38006F4529120G\n";
+
+    fn run(format: Format) -> (String, Summary) {
+        let mut output = Vec::new();
+        let summary = batch(LOG.as_bytes(), &mut output, format).unwrap();
+        (String::from_utf8(output).unwrap(), summary)
+    }
+
+    #[test]
+    fn test_csv() {
+        let (output, summary) = run(Format::Csv);
+        assert_eq!(output, r#"line,version_sum,value,packets,depth,error
+1,16,15,4,4,
+3,14,3,3,2,
+4,,,,,transmission has an odd number of hex digits (5)
+5,20,1,7,3,
+6,,,,,"bit 52: expected hex digit, found 'G'"
+"#);
+        assert_eq!(summary, Summary { transmissions: 5, failed: 2 });
+    }
+
+    #[test]
+    fn test_json() {
+        let (output, _) = run(Format::Json);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], r#"{"line":1,"version_sum":16,"value":15,"packets":4,"depth":4}"#);
+        assert_eq!(lines[2], r#"{"line":4,"error":"transmission has an odd number of hex digits (5)"}"#);
+        assert_eq!(lines.len(), 5);
+    }
+
+    #[test]
+    fn test_too_deep() {
+        let packet = (0..MAX_DEPTH).fold(Packet::literal(0, 1), |packet, _| {
+            Packet::operator(0, Operator::Sum, LengthType::Packets, vec![packet])
+        });
+        let log = format!("{}\nD2FE28\n", packet.encode().unwrap());
+        let mut output = Vec::new();
+        let summary = batch(log.as_bytes(), &mut output, Format::Csv).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().nth(1), Some("1,,,,,bit 4608: packet is nested more than 256 deep"));
+        assert_eq!(output.lines().nth(2), Some("2,6,2021,1,1,"));
+        assert_eq!(summary, Summary { transmissions: 2, failed: 1 });
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(csv_field(r#"say "hi", then"#), r#""say ""hi"", then""#);
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader};
+use std::process;
+
+use aoc_core::Args;
+use y2021_d16::Format;
+
+const USAGE: &str = "usage: bits [--input <path>] [--format csv|json]";
+
+// bits decodes a log of BITS transmissions, one per line, from --input or stdin.
+fn main() {
+    let result = Args::from_env().and_then(|args| match args.command() {
+        Some(argument) => Err(format!("unexpected argument {}\n{}", argument, USAGE)),
+        None => bits(&args),
+    });
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(2);
+    }
+}
+
+fn bits(args: &Args) -> Result<(), String> {
+    let format = args.parse_opt("format")?.unwrap_or(Format::Csv);
+    let reader: Box<dyn BufRead> = match args.get("input") {
+        Some(path) => Box::new(BufReader::new(File::open(path).map_err(|e| format!("{}: {}", path, e))?)),
+        None => Box::new(io::stdin().lock()),
+    };
+    let summary = y2021_d16::batch(reader, io::stdout().lock(), format).map_err(|e| e.to_string())?;
+    if summary.failed > 0 {
+        eprintln!("warning: {} of {} transmissions failed to decode", summary.failed, summary.transmissions);
+    }
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use aoc_core::AocError;
+
+use crate::packet::{Operator, PacketType};
+
+// DecodeError says what is wrong with a transmission. Offsets are in bits from the start of the
+// transmission.
+#[derive(Debug)]
+pub enum DecodeError {
+    // The transmission ended part way through a field, which context names.
+    Truncated { offset: u64, context: &'static str },
+    InvalidHex { offset: u64, found: char },
+    // Transmissions are whole bytes, so they have an even number of hex digits.
+    OddLength { digits: usize },
+    // A sub-packet ran past the number of bits its parent said its sub-packets take up.
+    Overrun { offset: u64, end: u64 },
+    // An operator packet had the wrong number of sub-packets for its operator.
+    Arity { offset: u64, operator: Operator, count: usize },
+    // The padding after the outermost packet had a 1 bit in it.
+    Padding { offset: u64 },
+    // A packet was nested inside more than max - 1 others.
+    TooDeep { offset: u64, max: usize },
+    Io(io::Error),
+}
+
+impl DecodeError {
+    // from_bits converts an error from reading the bits of context.
+    pub fn from_bits(e: aoc_bits::Error, context: &'static str) -> Self {
+        match e {
+            aoc_bits::Error::UnexpectedEnd { position, .. } => DecodeError::Truncated { offset: position, context },
+            aoc_bits::Error::InvalidHex { offset, found } => DecodeError::InvalidHex { offset: offset * 4, found },
+            aoc_bits::Error::Io(e) => DecodeError::Io(e),
+        }
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::Truncated { offset, context } => write!(f, "bit {}: transmission ends in the {}", offset, context),
+            DecodeError::InvalidHex { offset, found } => write!(f, "bit {}: expected hex digit, found {:?}", offset, found),
+            DecodeError::OddLength { digits } => write!(f, "transmission has an odd number of hex digits ({})", digits),
+            DecodeError::Overrun { offset, end } => {
+                write!(f, "bit {}: sub-packet runs past the end of its parent's sub-packets at bit {}", offset, end)
+            }
+            DecodeError::Arity { offset, operator, count } => {
+                write!(f, "bit {}: {} packet has {} sub-packets, expected {}", offset, operator, count, operator.arity())
+            }
+            DecodeError::Padding { offset } => write!(f, "bit {}: expected zero padding after the packet, found 1", offset),
+            DecodeError::TooDeep { offset, max } => write!(f, "bit {}: packet is nested more than {} deep", offset, max),
+            DecodeError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DecodeError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// EncodeError says that a field of a packet is too large for the bits it is encoded in. The offset
+// is where the packet starts, in bits from the start of the transmission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodeError {
+    pub offset: u64,
+    pub field: &'static str,
+    pub value: u64,
+    pub width: u32,
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bit {}: {} {} does not fit in {} bits", self.offset, self.field, self.value, self.width)
+    }
+}
+
+impl Error for EncodeError {}
+
+// Overflow says that a packet's value does not fit in the type it was evaluated in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub offset: u64,
+    pub packet_type: PacketType,
+    pub number: &'static str,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let packet = match self.packet_type {
+            PacketType::Literal => "literal".to_string(),
+            PacketType::Operator(operator) => operator.to_string(),
+        };
+        write!(f, "bit {}: value of {} packet does not fit in {}", self.offset, packet, self.number)
+    }
+}
+
+impl Error for Overflow {}
+
+// EvalError says why a packet could not be evaluated. Decoded packets always have the right
+// number of sub-packets for their operators and are never nested too deeply, but packets built
+// by hand may not be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    Overflow(Overflow),
+    Arity { offset: u64, operator: Operator, count: usize },
+    TooDeep { offset: u64, max: usize },
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Overflow(e) => write!(f, "{}", e),
+            EvalError::Arity { offset, operator, count } => {
+                write!(f, "bit {}: {} packet has {} sub-packets, expected {}", offset, operator, count, operator.arity())
+            }
+            EvalError::TooDeep { offset, max } => write!(f, "bit {}: packet is nested more than {} deep", offset, max),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+impl From<Overflow> for EvalError {
+    fn from(e: Overflow) -> Self {
+        EvalError::Overflow(e)
+    }
+}
+
+impl From<EvalError> for AocError {
+    fn from(e: EvalError) -> Self {
+        AocError::invalid(e.to_string())
+    }
+}
+
+impl From<EncodeError> for AocError {
+    fn from(e: EncodeError) -> Self {
+        AocError::invalid(e.to_string())
+    }
+}
+
+impl From<DecodeError> for AocError {
+    fn from(e: DecodeError) -> Self {
+        match e {
+            DecodeError::Io(e) => AocError::Io(e),
+            e => AocError::invalid(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::ast::{Body, MAX_DEPTH, Packet};
+use crate::error::{EvalError, Overflow};
+use crate::number::Number;
+use crate::packet::Operator;
+
+// version_sum adds up the versions of the packet and all of its sub-packets.
+pub fn version_sum(packet: &Packet) -> u32 {
+    match &packet.body {
+        Body::Literal(_) => packet.version,
+        Body::Operator { children, .. } => packet.version + children.iter().map(version_sum).sum::<u32>(),
+    }
+}
+
+// packet_count counts the packet and all of its sub-packets.
+pub fn packet_count(packet: &Packet) -> usize {
+    match &packet.body {
+        Body::Literal(_) => 1,
+        Body::Operator { children, .. } => 1 + children.iter().map(packet_count).sum::<usize>(),
+    }
+}
+
+// depth is the number of packets on the longest path from the packet down to a literal.
+pub fn depth(packet: &Packet) -> usize {
+    match &packet.body {
+        Body::Literal(_) => 1,
+        Body::Operator { children, .. } => 1 + children.iter().map(depth).max().unwrap_or(0),
+    }
+}
+
+// evaluate works out the value of the expression the packet stands for in N, or which packet's
+// value did not fit in N or has the wrong number of sub-packets for its operator.
+pub fn evaluate<N: Number>(packet: &Packet) -> Result<N, EvalError> {
+    evaluate_nested(packet, 1)
+}
+
+fn evaluate_nested<N: Number>(packet: &Packet, depth: usize) -> Result<N, EvalError> {
+    let offset = packet.span.start;
+    if depth > MAX_DEPTH {
+        return Err(EvalError::TooDeep { offset, max: MAX_DEPTH });
+    }
+    let overflow = || EvalError::from(Overflow { offset, packet_type: packet.packet_type(), number: N::NAME });
+    let (operator, children) = match &packet.body {
+        Body::Literal(literal) => return N::from_literal(literal).ok_or_else(overflow),
+        Body::Operator { operator, children, .. } => (*operator, children),
+    };
+    if !operator.arity().accepts(children.len()) {
+        return Err(EvalError::Arity { offset, operator, count: children.len() });
+    }
+
+    let values = children.iter().map(|child| evaluate_nested(child, depth + 1)).collect::<Result<Vec<N>, _>>()?;
+    let value = match operator {
+        Operator::Sum => values.iter().try_fold(N::from_u8(0), |sum, value| sum.checked_add(value)),
+        Operator::Product => values.iter().try_fold(N::from_u8(1), |product, value| product.checked_mul(value)),
+        Operator::Minimum => values.into_iter().min(),
+        Operator::Maximum => values.into_iter().max(),
+        Operator::GreaterThan => Some(N::from_u8((values[0] > values[1]) as u8)),
+        Operator::LessThan => Some(N::from_u8((values[0] < values[1]) as u8)),
+        Operator::EqualTo => Some(N::from_u8((values[0] == values[1]) as u8)),
+    };
+    value.ok_or_else(overflow)
+}
@@ -0,0 +1,232 @@
+use std::io::BufRead;
+
+use aoc_core::{Example, IntoInput, Result, Solution};
+use num_bigint::BigUint;
+
+pub use ast::{Body, MAX_DEPTH, Packet};
+pub use batch::{Format, Stats, Summary, batch};
+pub use error::{DecodeError, EncodeError, EvalError, Overflow};
+pub use eval::{depth, evaluate, packet_count, version_sum};
+pub use number::Number;
+pub use packet::{LengthType, Literal, Operator, PacketType};
+pub use render::tree;
+
+mod ast;
+mod batch;
+mod error;
+mod eval;
+mod number;
+mod packet;
+mod render;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Packet Decoder"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("D2FE28", "D2FE28", Some("6"), None),
+            Example::new("38006F45291200", "38006F45291200", Some("9"), None),
+            Example::new("8A004A801A8002F478", "8A004A801A8002F478", Some("16"), None),
+            Example::new("620080001611562C8802118E34", "620080001611562C8802118E34", Some("12"), None),
+            Example::new("C0015000016115A2E0802F182340", "C0015000016115A2E0802F182340", Some("23"), None),
+            Example::new("A0016C880162017C3686B18A3D4780", "A0016C880162017C3686B18A3D4780", Some("31"), None),
+            Example::new("C200B40A82", "C200B40A82", None, Some("3")),
+            Example::new("04005AC33890", "04005AC33890", None, Some("54")),
+            Example::new("880086C3E88112", "880086C3E88112", None, Some("7")),
+            Example::new("CE00C43D881120", "CE00C43D881120", None, Some("9")),
+            Example::new("D8005AC2A8F0", "D8005AC2A8F0", None, Some("1")),
+            Example::new("F600BC2D8F", "F600BC2D8F", None, Some("0")),
+            Example::new("9C005AC2F8F0", "9C005AC2F8F0", None, Some("0")),
+            Example::new("9C0141080250320F1802104A08", "9C0141080250320F1802104A08", None, Some("1")),
+        ]
+    }
+}
+
+pub fn part1<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let input = input.into_input()?;
+    let packet = Packet::decode(input.as_bytes())?;
+    Ok(version_sum(&packet).to_string())
+}
+
+pub fn part2<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let input = input.into_input()?;
+    let packet = Packet::decode(input.as_bytes())?;
+    Ok(evaluate::<BigUint>(&packet)?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new("D2FE28".as_bytes())).unwrap(), "6");
+        assert_eq!(part1(BufReader::new("38006F45291200".as_bytes())).unwrap(), "9");
+        assert_eq!(part1(BufReader::new("8A004A801A8002F478".as_bytes())).unwrap(), "16");
+        assert_eq!(part1(BufReader::new("620080001611562C8802118E34".as_bytes())).unwrap(), "12");
+        assert_eq!(part1(BufReader::new("C0015000016115A2E0802F182340".as_bytes())).unwrap(), "23");
+        assert_eq!(part1(BufReader::new("A0016C880162017C3686B18A3D4780".as_bytes())).unwrap(), "31");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new("C200B40A82".as_bytes())).unwrap(), "3");
+        assert_eq!(part2(BufReader::new("04005AC33890".as_bytes())).unwrap(), "54");
+        assert_eq!(part2(BufReader::new("880086C3E88112".as_bytes())).unwrap(), "7");
+        assert_eq!(part2(BufReader::new("CE00C43D881120".as_bytes())).unwrap(), "9");
+        assert_eq!(part2(BufReader::new("D8005AC2A8F0".as_bytes())).unwrap(), "1");
+        assert_eq!(part2(BufReader::new("F600BC2D8F".as_bytes())).unwrap(), "0");
+        assert_eq!(part2(BufReader::new("9C005AC2F8F0".as_bytes())).unwrap(), "0");
+        assert_eq!(part2(BufReader::new("9C0141080250320F1802104A08".as_bytes())).unwrap(), "1");
+    }
+
+    // next steps a xorshift generator, so that the random packets are the same on every run.
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    // random_packet builds a packet whose operators have the number of sub-packets they need.
+    // Literals are kept small so that nested sums and products cannot overflow.
+    fn random_packet(state: &mut u64, depth: u32) -> Packet {
+        let version = (next(state) % 8) as u32;
+        if depth == 0 || next(state).is_multiple_of(4) {
+            return Packet::literal(version, next(state) % 4);
+        }
+        let operator = [
+            Operator::Sum, Operator::Product, Operator::Minimum, Operator::Maximum,
+            Operator::GreaterThan, Operator::LessThan, Operator::EqualTo,
+        ][(next(state) % 7) as usize];
+        let count = match operator {
+            Operator::GreaterThan | Operator::LessThan | Operator::EqualTo => 2,
+            _ => 1 + next(state) % 3,
+        };
+        let length_type = if next(state).is_multiple_of(2) { LengthType::Bits } else { LengthType::Packets };
+        let children = (0..count).map(|_| random_packet(state, depth - 1)).collect();
+        Packet::operator(version, operator, length_type, children)
+    }
+
+    // expected works out the version sum and value of a packet straight from the tree.
+    fn expected(packet: &Packet) -> (u32, u64) {
+        match &packet.body {
+            Body::Literal(literal) => (packet.version, u64::from_literal(literal).unwrap()),
+            Body::Operator { operator, children, .. } => {
+                let (versions, values): (Vec<u32>, Vec<u64>) = children.iter().map(expected).unzip();
+                let value = match operator {
+                    Operator::Sum => values.iter().sum(),
+                    Operator::Product => values.iter().product(),
+                    Operator::Minimum => *values.iter().min().unwrap(),
+                    Operator::Maximum => *values.iter().max().unwrap(),
+                    Operator::GreaterThan => (values[0] > values[1]) as u64,
+                    Operator::LessThan => (values[0] < values[1]) as u64,
+                    Operator::EqualTo => (values[0] == values[1]) as u64,
+                };
+                (packet.version + versions.iter().sum::<u32>(), value)
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut state = 0x2021_1216;
+        for _ in 0..200 {
+            let packet = random_packet(&mut state, 3);
+            let hex = packet.encode().unwrap();
+            let (version, value) = expected(&packet);
+            assert_eq!(part1(hex.as_str()).unwrap(), version.to_string(), "{}", hex);
+            assert_eq!(part2(hex.as_str()).unwrap(), value.to_string(), "{}", hex);
+            assert_eq!(Packet::decode(hex.as_bytes()).unwrap(), packet, "{}", hex);
+        }
+
+        let hex = Packet::literal(3, u64::MAX).encode().unwrap();
+        assert_eq!(part2(hex.as_str()).unwrap(), u64::MAX.to_string());
+    }
+
+    #[test]
+    fn test_long_literal() {
+        let value = BigUint::from(u128::MAX) * 3u32;
+        let packet = Packet::with_literal(0, Literal::from(&value));
+        let hex = packet.encode().unwrap();
+        assert_eq!(Packet::decode(hex.as_bytes()).unwrap(), packet);
+        assert_eq!(part2(hex.as_str()).unwrap(), value.to_string());
+        assert_eq!(evaluate::<u128>(&packet).unwrap_err().to_string(), "bit 0: value of literal packet does not fit in u128");
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = Packet::literal(0, 1 << 40);
+        let packet = Packet::operator(0, Operator::Sum, LengthType::Packets, vec![
+            Packet::literal(0, 1),
+            Packet::operator(0, Operator::Product, LengthType::Packets, vec![big.clone(), big]),
+        ]);
+        let Body::Operator { children, .. } = &packet.body else { unreachable!() };
+        let offset = children[1].span.start;
+        assert_eq!(
+            evaluate::<u64>(&packet).unwrap_err(),
+            EvalError::Overflow(Overflow { offset, packet_type: PacketType::Operator(Operator::Product), number: "u64" }),
+        );
+        assert_eq!(evaluate::<u128>(&packet).unwrap(), (1 << 80) + 1);
+        assert_eq!(evaluate::<BigUint>(&packet).unwrap(), BigUint::from(1u128 << 80) + 1u32);
+        assert_eq!(part2(packet.encode().unwrap().as_str()).unwrap(), ((1u128 << 80) + 1).to_string());
+    }
+
+    #[test]
+    fn test_evaluate_bad_arity() {
+        // Packet::operator checks the number of sub-packets, but a packet built directly does not.
+        let minimum = Packet {
+            version: 0,
+            span: 0..0,
+            body: Body::Operator { operator: Operator::Minimum, length_type: LengthType::Packets, children: vec![] },
+        };
+        assert_eq!(
+            evaluate::<u64>(&minimum).unwrap_err().to_string(),
+            "bit 0: minimum packet has 0 sub-packets, expected at least 1",
+        );
+        assert!(Stats::of(&minimum).is_err());
+        assert_eq!(version_sum(&minimum), 0);
+
+        let mut greater = Packet::operator(0, Operator::Sum, LengthType::Packets, vec![Packet::literal(0, 1)]);
+        let Body::Operator { operator, .. } = &mut greater.body else { unreachable!() };
+        *operator = Operator::GreaterThan;
+        assert_eq!(
+            evaluate::<u64>(&greater).unwrap_err(),
+            EvalError::Arity { offset: 0, operator: Operator::GreaterThan, count: 1 },
+        );
+    }
+
+    #[test]
+    fn test_evaluate_too_deep() {
+        // Packets built directly have no spans, so the error is reported at bit 0.
+        let packet = (0..MAX_DEPTH).fold(Packet::literal(0, 1), |packet, _| Packet {
+            version: 0,
+            span: 0..0,
+            body: Body::Operator { operator: Operator::Sum, length_type: LengthType::Packets, children: vec![packet] },
+        });
+        assert_eq!(evaluate::<u64>(&packet).unwrap_err(), EvalError::TooDeep { offset: 0, max: MAX_DEPTH });
+        assert!(Stats::of(&packet).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use num_bigint::BigUint;
+
+use crate::packet::Literal;
+
+// Number is a type that packets can be evaluated in. Its operations return None when the result
+// does not fit, so that evaluation can report the overflow instead of wrapping.
+pub trait Number: Clone + Ord {
+    // NAME is the type's name, for errors.
+    const NAME: &'static str;
+
+    fn from_u8(n: u8) -> Self;
+    fn from_literal(literal: &Literal) -> Option<Self>;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn from_u8(n: u8) -> Self {
+                    n as $t
+                }
+
+                fn from_literal(literal: &Literal) -> Option<Self> {
+                    literal.nibbles().iter().try_fold(0 as $t, |value, &n| value.checked_mul(16)?.checked_add(n as $t))
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u64, u128);
+
+impl Number for BigUint {
+    const NAME: &'static str = "big integer";
+
+    fn from_u8(n: u8) -> Self {
+        BigUint::from(n)
+    }
+
+    fn from_literal(literal: &Literal) -> Option<Self> {
+        BigUint::from_radix_be(literal.nibbles(), 16)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_literal() {
+        let literal = Literal::from(u64::MAX);
+        assert_eq!(u64::from_literal(&literal), Some(u64::MAX));
+        assert_eq!(u128::from_literal(&literal), Some(u64::MAX as u128));
+
+        let literal = Literal::from(u64::MAX as u128 + 1);
+        assert_eq!(literal.groups(), 17);
+        assert_eq!(u64::from_literal(&literal), None);
+        assert_eq!(u128::from_literal(&literal), Some(u64::MAX as u128 + 1));
+        assert_eq!(BigUint::from_literal(&literal), Some(BigUint::from(u64::MAX) + 1u32));
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt::{Display, Formatter};
+
+use aoc_bits::{BitReader, BitWriter, Source};
+use num_bigint::BigUint;
+
+use crate::error::DecodeError;
+
+pub trait ReadFrom<S: Source> {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> where Self: Sized;
+}
+
+// read reads n bits of context, which names them for errors.
+fn read<S: Source>(reader: &mut BitReader<S>, n: u32, context: &'static str) -> Result<u64, DecodeError> {
+    reader.read(n).map_err(|e| DecodeError::from_bits(e, context))
+}
+
+// WriteTo writes things in the form that ReadFrom reads them back.
+pub trait WriteTo {
+    fn write_to(&self, writer: &mut BitWriter);
+}
+
+pub struct PacketHeader {
+    pub version: Version,
+    pub packet_type: PacketType,
+}
+
+impl WriteTo for PacketHeader {
+    fn write_to(&self, writer: &mut BitWriter) {
+        self.version.write_to(writer);
+        self.packet_type.write_to(writer);
+    }
+}
+
+impl<S: Source> ReadFrom<S> for PacketHeader {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        let version = Version::read_from(reader)?;
+        let packet_type = PacketType::read_from(reader)?;
+        Ok(PacketHeader { version, packet_type })
+    }
+}
+
+pub struct Version(u32);
+
+impl From<Version> for u32 {
+    fn from(val: Version) -> Self {
+        val.0
+    }
+}
+
+impl From<u32> for Version {
+    fn from(val: u32) -> Self {
+        Version(val)
+    }
+}
+
+impl WriteTo for Version {
+    fn write_to(&self, writer: &mut BitWriter) {
+        writer.write(self.0 as u64, 3);
+    }
+}
+
+impl<S: Source> ReadFrom<S> for Version {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        Ok(Version(read(reader, 3, "version")? as u32))
+    }
+}
+
+pub struct Length {
+    pub length_type: LengthType,
+    pub length: u64,
+}
+
+impl WriteTo for Length {
+    fn write_to(&self, writer: &mut BitWriter) {
+        self.length_type.write_to(writer);
+        writer.write(self.length, self.length_type.width());
+    }
+}
+
+impl<S: Source> ReadFrom<S> for Length {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        let length_type = LengthType::read_from(reader)?;
+        let length = read(reader, length_type.width(), length_type.field())?;
+        Ok(Length { length_type, length })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LengthType {
+    Bits,
+    Packets,
+}
+
+impl LengthType {
+    // id is the bit that marks the length type.
+    pub fn id(self) -> u64 {
+        match self {
+            LengthType::Bits => 0,
+            LengthType::Packets => 1,
+        }
+    }
+
+    // field names a length of this type.
+    pub fn field(self) -> &'static str {
+        match self {
+            LengthType::Bits => "length of the sub-packets",
+            LengthType::Packets => "number of sub-packets",
+        }
+    }
+
+    // width is the number of bits in a length of this type.
+    pub fn width(self) -> u32 {
+        match self {
+            LengthType::Bits => 15,
+            LengthType::Packets => 11,
+        }
+    }
+}
+
+impl WriteTo for LengthType {
+    fn write_to(&self, writer: &mut BitWriter) {
+        writer.write(self.id(), 1);
+    }
+}
+
+impl<S: Source> ReadFrom<S> for LengthType {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        Ok(if read(reader, 1, "length type ID")? == 0 { LengthType::Bits } else { LengthType::Packets })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PacketType {
+    Literal,
+    Operator(Operator),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operator {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl PacketType {
+    // ALL lists the packet types in the order of their IDs.
+    const ALL: [PacketType; 8] = [
+        PacketType::Operator(Operator::Sum),
+        PacketType::Operator(Operator::Product),
+        PacketType::Operator(Operator::Minimum),
+        PacketType::Operator(Operator::Maximum),
+        PacketType::Literal,
+        PacketType::Operator(Operator::GreaterThan),
+        PacketType::Operator(Operator::LessThan),
+        PacketType::Operator(Operator::EqualTo),
+    ];
+
+    pub fn id(self) -> u64 {
+        match self {
+            PacketType::Operator(Operator::Sum) => 0,
+            PacketType::Operator(Operator::Product) => 1,
+            PacketType::Operator(Operator::Minimum) => 2,
+            PacketType::Operator(Operator::Maximum) => 3,
+            PacketType::Literal => 4,
+            PacketType::Operator(Operator::GreaterThan) => 5,
+            PacketType::Operator(Operator::LessThan) => 6,
+            PacketType::Operator(Operator::EqualTo) => 7,
+        }
+    }
+}
+
+impl WriteTo for PacketType {
+    fn write_to(&self, writer: &mut BitWriter) {
+        writer.write(self.id(), 3);
+    }
+}
+
+impl<S: Source> ReadFrom<S> for PacketType {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        // Every 3 bit type ID means something, so this cannot go out of range.
+        Ok(PacketType::ALL[read(reader, 3, "type ID")? as usize])
+    }
+}
+
+impl Operator {
+    pub fn arity(self) -> Arity {
+        match self {
+            Operator::GreaterThan | Operator::LessThan | Operator::EqualTo => Arity::Two,
+            _ => Arity::AtLeastOne,
+        }
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Operator::Sum => "sum",
+            Operator::Product => "product",
+            Operator::Minimum => "minimum",
+            Operator::Maximum => "maximum",
+            Operator::GreaterThan => "greater than",
+            Operator::LessThan => "less than",
+            Operator::EqualTo => "equal to",
+        })
+    }
+}
+
+// Arity is the number of sub-packets an operator takes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Arity {
+    AtLeastOne,
+    Two,
+}
+
+impl Arity {
+    pub fn accepts(self, count: usize) -> bool {
+        match self {
+            Arity::AtLeastOne => count >= 1,
+            Arity::Two => count == 2,
+        }
+    }
+}
+
+impl Display for Arity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Arity::AtLeastOne => "at least 1",
+            Arity::Two => "2",
+        })
+    }
+}
+
+pub enum LiteralGroup {
+    More(u64),
+    Final(u64),
+}
+
+impl WriteTo for LiteralGroup {
+    fn write_to(&self, writer: &mut BitWriter) {
+        let (more, value) = match *self {
+            LiteralGroup::More(v) => (1, v),
+            LiteralGroup::Final(v) => (0, v),
+        };
+        writer.write(more, 1);
+        writer.write(value, 4);
+    }
+}
+
+impl<S: Source> ReadFrom<S> for LiteralGroup {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        let more = read(reader, 1, "literal")?;
+        let value = read(reader, 4, "literal")?;
+        Ok(if more == 1 { LiteralGroup::More(value) } else { LiteralGroup::Final(value) })
+    }
+}
+
+// Literal is the value of a literal packet, as its groups of 4 bits, most significant first. It
+// keeps every group, so it can hold values of any size; leading zero groups are dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Literal(Vec<u8>);
+
+impl<S: Source> ReadFrom<S> for Literal {
+    fn read_from(reader: &mut BitReader<S>) -> Result<Self, DecodeError> {
+        let mut nibbles = Vec::new();
+        loop {
+            match LiteralGroup::read_from(reader)? {
+                LiteralGroup::More(v) => nibbles.push(v as u8),
+                LiteralGroup::Final(v) => {
+                    nibbles.push(v as u8);
+                    return Ok(Literal::from_nibbles(nibbles));
+                }
+            }
+        }
+    }
+}
+
+impl Literal {
+    fn from_nibbles(mut nibbles: Vec<u8>) -> Self {
+        let zeros = nibbles.iter().take_while(|&&n| n == 0).count().min(nibbles.len() - 1);
+        nibbles.drain(..zeros);
+        Literal(nibbles)
+    }
+
+    // groups returns the number of groups of 4 bits needed to write the literal.
+    pub fn groups(&self) -> u32 {
+        self.0.len() as u32
+    }
+
+    pub fn nibbles(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl WriteTo for Literal {
+    fn write_to(&self, writer: &mut BitWriter) {
+        for (i, &nibble) in self.0.iter().enumerate() {
+            let value = nibble as u64;
+            let group = if i + 1 == self.0.len() { LiteralGroup::Final(value) } else { LiteralGroup::More(value) };
+            group.write_to(writer);
+        }
+    }
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", BigUint::from_radix_be(&self.0, 16).unwrap())
+    }
+}
+
+impl From<u64> for Literal {
+    fn from(val: u64) -> Self {
+        Literal::from(val as u128)
+    }
+}
+
+impl From<u128> for Literal {
+    fn from(val: u128) -> Self {
+        Literal::from_nibbles((0..32).rev().map(|i| (val >> (i * 4) & 0xF) as u8).collect())
+    }
+}
+
+impl From<&BigUint> for Literal {
+    fn from(val: &BigUint) -> Self {
+        Literal(val.to_radix_be(16))
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter, Write};
+
+use crate::ast::{Body, Packet};
+use crate::packet::Operator;
+
+// tree draws the packet a line per packet, with sub-packets indented under their parents.
+pub fn tree(packet: &Packet) -> String {
+    let mut output = String::new();
+    draw(packet, "", "", &mut output);
+    output
+}
+
+fn draw(packet: &Packet, connector: &str, indent: &str, output: &mut String) {
+    let Packet { version, span, body } = packet;
+    let children = match body {
+        Body::Literal(value) => {
+            writeln!(output, "{}literal {} v{} bits {}..{}", connector, value, version, span.start, span.end).unwrap();
+            return;
+        }
+        Body::Operator { operator, children, .. } => {
+            writeln!(output, "{}{} v{} bits {}..{}", connector, operator, version, span.start, span.end).unwrap();
+            children
+        }
+    };
+
+    for (i, child) in children.iter().enumerate() {
+        if i + 1 < children.len() {
+            draw(child, &format!("{}├─ ", indent), &format!("{}│  ", indent), output);
+        } else {
+            draw(child, &format!("{}└─ ", indent), &format!("{}   ", indent), output);
+        }
+    }
+}
+
+// Packets are displayed as the expression they stand for, such as "(3 + (5 * 7)) > 2".
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        infix(self, false, f)
+    }
+}
+
+// infix writes the packet as an expression. Nested operations are bracketed so that the
+// expression reads the same without knowing any precedence rules.
+fn infix(packet: &Packet, nested: bool, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let (operator, children) = match &packet.body {
+        Body::Literal(value) => return write!(f, "{}", value),
+        Body::Operator { operator, children, .. } => (*operator, children),
+    };
+
+    let symbol = match operator {
+        Operator::Sum => "+",
+        Operator::Product => "*",
+        Operator::GreaterThan => ">",
+        Operator::LessThan => "<",
+        Operator::EqualTo => "==",
+        Operator::Minimum => return call(f, "min", children),
+        Operator::Maximum => return call(f, "max", children),
+    };
+
+    match children.as_slice() {
+        [] => call(f, &operator.to_string(), children),
+        [child] => infix(child, nested, f),
+        _ => {
+            if nested {
+                write!(f, "(")?;
+            }
+            for (i, child) in children.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {} ", symbol)?;
+                }
+                infix(child, true, f)?;
+            }
+            if nested {
+                write!(f, ")")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn call(f: &mut Formatter<'_>, name: &str, arguments: &[Packet]) -> std::fmt::Result {
+    write!(f, "{}(", name)?;
+    for (i, argument) in arguments.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        infix(argument, false, f)?;
+    }
+    write!(f, ")")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::packet::LengthType;
+
+    use super::*;
+
+    fn operator(operator: Operator, children: Vec<Packet>) -> Packet {
+        Packet::operator(0, operator, LengthType::Packets, children)
+    }
+
+    fn literal(value: u64) -> Packet {
+        Packet::literal(0, value)
+    }
+
+    #[test]
+    fn test_infix() {
+        let packet = operator(Operator::GreaterThan, vec![
+            operator(Operator::Sum, vec![literal(3), operator(Operator::Product, vec![literal(5), literal(7)])]),
+            literal(2),
+        ]);
+        assert_eq!(packet.to_string(), "(3 + (5 * 7)) > 2");
+
+        let packet = operator(Operator::Maximum, vec![
+            operator(Operator::Sum, vec![literal(1), literal(2), literal(3)]),
+            operator(Operator::Product, vec![literal(4)]),
+            operator(Operator::Minimum, vec![literal(5)]),
+        ]);
+        assert_eq!(packet.to_string(), "max(1 + 2 + 3, 4, min(5))");
+
+        // Packet::operator will not build an empty operator, but a Body can still be written out.
+        let empty = Body::Operator { operator: Operator::Sum, length_type: LengthType::Packets, children: vec![] };
+        assert_eq!(Packet { version: 0, span: 0..0, body: empty }.to_string(), "sum()");
+    }
+
+    #[test]
+    fn test_tree() {
+        let packet = Packet::operator(1, Operator::Sum, LengthType::Bits, vec![
+            Packet::literal(6, 10),
+            Packet::operator(2, Operator::EqualTo, LengthType::Packets, vec![Packet::literal(3, 1), Packet::literal(4, 1)]),
+            Packet::literal(5, 20),
+        ]);
+        assert_eq!(tree(&packet), "\
+sum v1 bits 0..89
+├─ literal 10 v6 bits 22..33
+├─ equal to v2 bits 33..73
+│  ├─ literal 1 v3 bits 51..62
+│  └─ literal 1 v4 bits 62..73
+└─ literal 20 v5 bits 73..89
+");
+    }
+}
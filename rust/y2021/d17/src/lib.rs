@@ -0,0 +1,170 @@
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+use aoc_core::{AocError, Example, Line, lines, Result, Solution};
+use aoc_parse::{pair, parse_line, prefixed, range};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Trick Shot"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("45"), Some("112")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let target_area = parse_input(reader)?;
+    Ok((0..-target_area.y.start()).sum::<i32>().to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let target_area = parse_input(reader)?;
+
+    let min_vy = *target_area.y.start();
+    let max_vy = -target_area.y.start() - 1;
+    // Using the quadratic formula to solve for "n" in the Triangular number formula.
+    // See: https://en.wikipedia.org/wiki/Triangular_number#Formula
+    // This is a minor optimization over just using "0" as the min x velocity.
+    let min_vx = (-1 + (((1 + 8 * target_area.x.start()) as f32).sqrt() as i32)) / 2;
+    let max_vx = *target_area.x.end();
+
+    Ok((min_vy..=max_vy)
+        .map(|vy| simulate_y_get_steps(vy, &target_area.y))
+        .map(|steps| (min_vx..=max_vx)
+            .filter(|&vx| simulate_x(vx, &target_area.x, &steps))
+            .count())
+        .sum::<usize>()
+        .to_string())
+}
+
+fn simulate_y_get_steps(vy: i32, target_y: &RangeInclusive<i32>) -> Vec<i32> {
+    let mut output = Vec::new();
+    let mut vy = vy;
+    let mut py = 0;
+    let mut steps = 0;
+
+    while py > *target_y.start() {
+        steps += 1;
+        py += vy;
+        vy -= 1;
+
+        if target_y.contains(&py) {
+            output.push(steps)
+        }
+    }
+
+    output
+}
+
+fn simulate_x(vx: i32, target_x: &RangeInclusive<i32>, steps: &Vec<i32>) -> bool {
+    let mut vx = vx;
+    let mut px = 0;
+    let mut step = 0;
+
+    for want_step in steps {
+        while step < *want_step {
+            step += 1;
+            px += vx;
+            vx = match vx.cmp(&0) {
+                std::cmp::Ordering::Equal => 0,
+                std::cmp::Ordering::Less => vx + 1,
+                std::cmp::Ordering::Greater => vx - 1,
+            }
+        }
+
+        if target_x.contains(&px) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Area> {
+    let line = lines(reader).expect("target area")?;
+    let (x, y) = parse_line(&line, prefixed("target area: ", pair(prefixed("x=", bounded), ", ", prefixed("y=", bounded))))?;
+    let area = Area { x, y };
+
+    // The solutions rely on the target being ahead of and below the probe's starting position.
+    if *area.x.start() <= 0 || *area.y.end() >= 0 {
+        return Err(AocError::no_solution("target area must be ahead of and below the probe"));
+    }
+
+    Ok(area)
+}
+
+const MAX_COORDINATE: i32 = 1000;
+
+// Coordinates are bounded so that the highest point of a trajectory fits in an i32 and the
+// search over velocities stays quick.
+fn bounded(line: &Line, s: &str) -> Result<RangeInclusive<i32>> {
+    let range = range(line, s)?;
+    if *range.start() < -MAX_COORDINATE || *range.end() > MAX_COORDINATE {
+        return Err(line.error(s, format!("range within -{}..{}", MAX_COORDINATE, MAX_COORDINATE)));
+    }
+    Ok(range)
+}
+
+struct Area {
+    x: RangeInclusive<i32>,
+    y: RangeInclusive<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "45")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "112")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("target area: x=20..30, y=-10...-5".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 31: expected range end, found \".-5\"");
+        let err = part1(BufReader::new("target area: x=20..30 y=-10..-5".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 14: expected \", \", found \"x=20..30 y=-10..-5\"");
+        let err = part1(BufReader::new("target area: x=30..20, y=-10..-5".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 16: expected ascending range, found \"30..20\"");
+        let err = part1(BufReader::new("target area: x=20..30, y=-2147483648..-1".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 26: expected range within -1000..1000, found \"-2147483648..-1\"");
+    }
+
+    #[test]
+    fn test_widest_target() {
+        assert_eq!(part1(BufReader::new("target area: x=1000..1000, y=-1000..-1000".as_bytes())).unwrap(), "499500");
+        assert_eq!(part2(BufReader::new("target area: x=1000..1000, y=-1000..-1000".as_bytes())).unwrap(), "4");
+    }
+}
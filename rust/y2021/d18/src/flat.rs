@@ -0,0 +1,168 @@
+use std::iter::Sum;
+use std::ops::Add;
+
+use crate::Number;
+use crate::rules::SnailfishRules;
+
+// FlatNumber is a snailfish number stored as its regular numbers from left to right, each tagged
+// with how many pairs it is nested inside. It has the same arithmetic as Number, but adding two of
+// them copies a pair of short vectors rather than cloning and walking boxed trees.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct FlatNumber {
+    regulars: Vec<Regular>,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+struct Regular {
+    value: i32,
+    depth: u32,
+}
+
+impl FlatNumber {
+    // add_with adds two numbers that are already reduced under the rules, which the numbers of a
+    // sum always are, giving the same result as Number::add_with.
+    pub(crate) fn add_with(&self, rhs: &FlatNumber, rules: &SnailfishRules) -> FlatNumber {
+        let regulars = self.regulars.iter().chain(&rhs.regulars)
+            .map(|r| Regular { value: r.value, depth: r.depth + 1 })
+            .collect();
+        let mut output = FlatNumber { regulars };
+        output.reduce(rules);
+        output
+    }
+
+    // reduce gives the same result as Number::reduce as long as no regular number is nested more
+    // than one pair too deeply: it always explodes the leftmost pair that is too deep before
+    // splitting the leftmost regular number that is too large.
+    fn reduce(&mut self, rules: &SnailfishRules) {
+        debug_assert!(rules.validate().is_ok(), "rules are validated before reducing");
+
+        // Exploding never nests a pair more deeply, so every explosion can happen in one pass.
+        let mut i = 0;
+        while i < self.regulars.len() {
+            if self.too_deep(i, rules) {
+                self.explode(i);
+            }
+            i += 1;
+        }
+
+        // Splitting can only push a pair too deep when it creates that pair, which then explodes
+        // straight away. That may in turn leave the number to its left needing a split, so the
+        // search picks up from there.
+        let mut i = 0;
+        while i < self.regulars.len() {
+            if !rules.splits(self.regulars[i].value) {
+                i += 1;
+                continue;
+            }
+            self.split(i, rules);
+            if self.too_deep(i, rules) {
+                self.explode(i);
+                i = i.saturating_sub(1);
+            }
+        }
+    }
+
+    // too_deep returns whether the regular number at i is in a pair that explodes.
+    fn too_deep(&self, i: usize, rules: &SnailfishRules) -> bool {
+        let depth = self.regulars[i].depth as usize;
+        depth > 0 && rules.explodes(depth - 1)
+    }
+
+    // explode replaces the pair whose left number is at i with 0, adding its numbers to the
+    // numbers either side of it.
+    fn explode(&mut self, i: usize) {
+        let Regular { value: left, depth } = self.regulars[i];
+        let right = self.regulars[i + 1];
+        assert_eq!(right.depth, depth, "only pairs of regular numbers explode");
+        if i > 0 {
+            self.regulars[i - 1].value += left;
+        }
+        if let Some(next) = self.regulars.get_mut(i + 2) {
+            next.value += right.value;
+        }
+        self.regulars[i] = Regular { value: 0, depth: depth - 1 };
+        self.regulars.remove(i + 1);
+    }
+
+    // split replaces the number at i with a pair of its halves.
+    fn split(&mut self, i: usize, rules: &SnailfishRules) {
+        let Regular { value, depth } = self.regulars[i];
+        let (left, right) = rules.split(value);
+        self.regulars[i] = Regular { value: left, depth: depth + 1 };
+        self.regulars.insert(i + 1, Regular { value: right, depth: depth + 1 });
+    }
+
+    pub(crate) fn magnitude(&self, rules: &SnailfishRules) -> Option<i32> {
+        // The stack holds the magnitudes of the pairs and numbers that are waiting for their
+        // right-hand sibling, which is complete once it is at the same depth.
+        let mut stack: Vec<Regular> = Vec::new();
+        for &regular in &self.regulars {
+            let mut current = regular;
+            while let Some(&left) = stack.last().filter(|left| left.depth == current.depth && current.depth > 0) {
+                stack.pop();
+                current = Regular { value: rules.magnitude(left.value, current.value)?, depth: current.depth - 1 };
+            }
+            stack.push(current);
+        }
+        assert_eq!(stack.len(), 1, "a snailfish number has one outermost pair");
+        Some(stack[0].value)
+    }
+}
+
+impl Add<&FlatNumber> for &FlatNumber {
+    type Output = FlatNumber;
+
+    fn add(self, rhs: &FlatNumber) -> Self::Output {
+        self.add_with(rhs, &SnailfishRules::PUZZLE)
+    }
+}
+
+impl Add for FlatNumber {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Sum for FlatNumber {
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.reduce(|acc, cur| acc + cur).unwrap()
+    }
+}
+
+impl From<&Number> for FlatNumber {
+    fn from(number: &Number) -> Self {
+        fn flatten(number: &Number, depth: u32, regulars: &mut Vec<Regular>) {
+            match number {
+                Number::Single(value) => regulars.push(Regular { value: *value, depth }),
+                Number::Pair(l, r) => {
+                    flatten(l, depth + 1, regulars);
+                    flatten(r, depth + 1, regulars);
+                }
+            }
+        }
+
+        let mut regulars = Vec::new();
+        flatten(number, 0, &mut regulars);
+        FlatNumber { regulars }
+    }
+}
+
+impl From<&FlatNumber> for Number {
+    fn from(number: &FlatNumber) -> Self {
+        fn build(regulars: &mut std::slice::Iter<Regular>, depth: u32) -> Number {
+            let next = regulars.as_slice().first().expect("flat number ends part way through a pair");
+            if next.depth == depth {
+                regulars.next();
+                Number::Single(next.value)
+            } else {
+                let l = build(regulars, depth + 1);
+                let r = build(regulars, depth + 1);
+                Number::Pair(Box::new(l), Box::new(r))
+            }
+        }
+
+        build(&mut number.regulars.iter(), 0)
+    }
+}
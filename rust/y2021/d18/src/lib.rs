@@ -0,0 +1,338 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use aoc_core::{AocError, Example, Line, lines, Part, Result, Solution};
+
+use crate::flat::FlatNumber;
+
+pub use rules::{Rounding, SnailfishRules};
+
+mod flat;
+mod rules;
+mod trace;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "Snailfish"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("4140"), Some("3993")),
+        ]
+    }
+
+    fn explain(&self, part: Part, reader: &mut dyn BufRead) -> Result<Option<String>> {
+        let numbers = parse_input(reader)?;
+        Ok(Some(match part {
+            Part::One => trace::explain(numbers, &SnailfishRules::PUZZLE)?,
+            Part::Two => match largest_sum(&numbers, &SnailfishRules::PUZZLE)? {
+                Some(((i, j), _)) => {
+                    trace::explain(vec![numbers[i].clone(), numbers[j].clone()], &SnailfishRules::PUZZLE)?
+                }
+                None => String::from("no two numbers to add\n"),
+            },
+        }))
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    part1_with(reader, &SnailfishRules::PUZZLE)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    part2_with(reader, &SnailfishRules::PUZZLE)
+}
+
+// part1_with and part2_with solve the puzzle under a variant of the rules of snailfish arithmetic.
+// They fail if a magnitude does not fit in an i32, which large magnitude weights soon cause.
+pub fn part1_with<R: BufRead>(reader: R, rules: &SnailfishRules) -> Result<String> {
+    rules.validate()?;
+    Ok(parse_input(reader)?
+        .into_iter()
+        .reduce(|sum, number| sum.add_with(number, rules))
+        .unwrap()
+        .magnitude(rules)
+        .ok_or_else(magnitude_overflow)?
+        .to_string())
+}
+
+pub fn part2_with<R: BufRead>(reader: R, rules: &SnailfishRules) -> Result<String> {
+    rules.validate()?;
+    let numbers = parse_input(reader)?;
+    let max_magnitude = largest_sum(&numbers, rules)?.map_or(0, |(_, magnitude)| magnitude);
+    Ok(max_magnitude.to_string())
+}
+
+fn magnitude_overflow() -> AocError {
+    AocError::invalid("magnitude does not fit in 32 bits")
+}
+
+// largest_sum finds the indexes of the two different numbers whose sum has the largest magnitude,
+// along with that magnitude.
+fn largest_sum(numbers: &[Number], rules: &SnailfishRules) -> Result<Option<((usize, usize), i32)>> {
+    // Flat numbers can only be added once they are reduced, which the input's numbers may not be
+    // under rules other than the puzzle's.
+    let numbers = numbers.iter()
+        .map(|number| {
+            let mut number = number.clone();
+            number.reduce(rules);
+            FlatNumber::from(&number)
+        })
+        .collect::<Vec<_>>();
+
+    let mut largest = None;
+
+    for i in 0..numbers.len() {
+        for j in 0..numbers.len() {
+            if i != j {
+                let sum = numbers[i].add_with(&numbers[j], rules);
+                let magnitude = sum.magnitude(rules).ok_or_else(magnitude_overflow)?;
+                if largest.is_none_or(|(_, max_magnitude)| magnitude > max_magnitude) {
+                    largest = Some(((i, j), magnitude));
+                }
+            }
+        }
+    }
+
+    Ok(largest)
+}
+
+// Exploding and splitting never make the regular numbers of a sum add up to more than those of
+// the numbers added, so bounding the total of the whole input means that no regular number can
+// overflow however the numbers are added.
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Number>> {
+    let mut numbers = Vec::new();
+    let mut total = 0i32;
+    for line in lines(reader) {
+        let line = line?;
+        let number = Number::parse(&line)?;
+        total = number.total().and_then(|n| total.checked_add(n)).ok_or_else(|| line.error(&line.text, TOTAL))?;
+        numbers.push(number);
+    }
+
+    if numbers.is_empty() {
+        return Err(AocError::end_of_input(1, "snailfish number"));
+    }
+    Ok(numbers)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Number {
+    Single(i32),
+    Pair(Box<Number>, Box<Number>),
+}
+
+impl Number {
+    fn reduce(&mut self, rules: &SnailfishRules) {
+        debug_assert!(rules.validate().is_ok(), "rules are validated before reducing");
+        while self.reduce_explode_once(rules) || self.reduce_split_once(rules) {}
+    }
+
+    fn reduce_explode_once(&mut self, rules: &SnailfishRules) -> bool {
+        self.reduce_explode_once_depth(0, rules).is_some()
+    }
+
+    // reduce_explode_once_depth explodes the leftmost pair of regular numbers that is deep
+    // enough, where depth is the number of pairs that self is nested inside.
+    fn reduce_explode_once_depth(&mut self, depth: usize, rules: &SnailfishRules) -> Option<(i32, i32)> {
+        match self {
+            Number::Pair(l, r) => {
+                if rules.explodes(depth) && matches!((&**l, &**r), (Number::Single(_), Number::Single(_))) {
+                    let explosion = Some(self.get_exploding_numbers());
+                    *self = Number::Single(0);
+                    explosion
+                } else if let Some(explosion) = l.reduce_explode_once_depth(depth + 1, rules) {
+                    r.propagate_explosion_left(explosion.1);
+                    Some((explosion.0, 0))
+                } else if let Some(explosion) = r.reduce_explode_once_depth(depth + 1, rules) {
+                    l.propagate_explosion_right(explosion.0);
+                    Some((0, explosion.1))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn propagate_explosion_left(&mut self, explosion: i32) {
+        if explosion > 0 {
+            match self {
+                Number::Single(n) => *self = Number::Single(*n + explosion),
+                Number::Pair(l, _) => l.propagate_explosion_left(explosion),
+            }
+        }
+    }
+
+    fn propagate_explosion_right(&mut self, explosion: i32) {
+        if explosion > 0 {
+            match self {
+                Number::Single(n) => *self = Number::Single(*n + explosion),
+                Number::Pair(_, r) => r.propagate_explosion_right(explosion),
+            }
+        }
+    }
+
+    fn get_exploding_numbers(&self) -> (i32, i32) {
+        match self {
+            Number::Pair(l, r) => (
+                match **l {
+                    Number::Single(n) => n,
+                    _ => unreachable!(),
+                },
+                match **r {
+                    Number::Single(n) => n,
+                    _ => unreachable!(),
+                },
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    fn reduce_split_once(&mut self, rules: &SnailfishRules) -> bool {
+        match self {
+            Number::Single(n) => {
+                let n = *n;
+                if rules.splits(n) {
+                    let (left, right) = rules.split(n);
+                    *self = Number::Pair(
+                        Box::new(Number::Single(left)),
+                        Box::new(Number::Single(right)),
+                    );
+                    true
+                } else {
+                    false
+                }
+            }
+            Number::Pair(l, r) => l.reduce_split_once(rules) || r.reduce_split_once(rules)
+        }
+    }
+
+    fn magnitude(&self, rules: &SnailfishRules) -> Option<i32> {
+        match self {
+            Number::Single(n) => Some(*n),
+            Number::Pair(l, r) => rules.magnitude(l.magnitude(rules)?, r.magnitude(rules)?),
+        }
+    }
+
+    fn add_with(self, rhs: Number, rules: &SnailfishRules) -> Number {
+        let mut output = Number::Pair(Box::new(self), Box::new(rhs));
+        output.reduce(rules);
+        output
+    }
+}
+
+// Numbers are displayed the way the puzzle writes them, such as "[[1,2],3]".
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Number::Single(n) => write!(f, "{}", n),
+            Number::Pair(l, r) => write!(f, "[{},{}]", l, r),
+        }
+    }
+}
+
+impl Add for Number {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_with(rhs, &SnailfishRules::PUZZLE)
+    }
+}
+
+impl Sum for Number {
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.reduce(|acc, cur| acc + cur).unwrap()
+    }
+}
+
+const TOTAL: &str = "regular numbers adding up to at most 2147483647";
+const MAX_REGULAR: i32 = 9999;
+
+impl Number {
+    // parse parses a whole snailfish number, which is always a pair, though the pairs inside it
+    // may hold regular numbers.
+    fn parse(line: &Line) -> Result<Self> {
+        if !line.text.starts_with('[') {
+            return Err(line.error(&line.text, "\"[\""));
+        }
+        let (number, rest) = Number::parse_prefix(line, &line.text)?;
+        if !rest.is_empty() {
+            return Err(line.error(rest, "end of line"));
+        }
+        if number.total().is_none() {
+            return Err(line.error(&line.text, TOTAL));
+        }
+        Ok(number)
+    }
+
+    // total adds up the regular numbers, or returns None if they do not fit in an i32.
+    fn total(&self) -> Option<i32> {
+        match self {
+            Number::Single(n) => Some(*n),
+            Number::Pair(l, r) => l.total()?.checked_add(r.total()?),
+        }
+    }
+
+    // parse_prefix parses a number from the start of s, returning it along with the unparsed rest.
+    fn parse_prefix<'a>(line: &Line, s: &'a str) -> Result<(Self, &'a str)> {
+        match s.strip_prefix('[') {
+            Some(rest) => {
+                let (left, rest) = Number::parse_prefix(line, rest)?;
+                let rest = line.strip_prefix(rest, ",")?;
+                let (right, rest) = Number::parse_prefix(line, rest)?;
+                let rest = line.strip_prefix(rest, "]")?;
+                Ok((Number::Pair(Box::new(left), Box::new(right)), rest))
+            }
+            None => {
+                let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+                if end == 0 {
+                    return Err(line.error(s, "regular number or \"[\""));
+                }
+                // Leading zeros would not survive being displayed again.
+                if end > 1 && s.starts_with('0') {
+                    return Err(line.error(s, "regular number without leading zeros"));
+                }
+                // Splitting a large number takes time in proportion to its size.
+                match s[..end].parse() {
+                    Ok(value) if value <= MAX_REGULAR => Ok((Number::Single(value), &s[end..])),
+                    _ => Err(line.error(s, format!("regular number up to {}", MAX_REGULAR))),
+                }
+            }
+        }
+    }
+}
+
+// Numbers parse from a single line, so errors are reported at line 1.
+impl FromStr for Number {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Number::parse(&Line::new(1, s))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,70 @@
+use aoc_core::{AocError, Result};
+
+// SnailfishRules are the constants of snailfish arithmetic, so that variants of the puzzle can be
+// tried out. PUZZLE is the puzzle's own rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnailfishRules {
+    // A pair of regular numbers nested inside at least this many other pairs explodes. It must be
+    // at least 1, or a sum would explode into a regular number.
+    pub explode_depth: usize,
+    // A regular number at least this large splits. It must be at least 2, or splitting never ends.
+    pub split_threshold: i32,
+    pub split_rounding: Rounding,
+    // The magnitude of a pair is the magnitudes of its left and right halves times these weights.
+    // Large weights soon give magnitudes that do not fit in an i32, which are reported as errors.
+    pub magnitude_weights: (i32, i32),
+}
+
+// Rounding says which way the left half of a split number is rounded; the right half is the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+impl SnailfishRules {
+    pub const PUZZLE: SnailfishRules = SnailfishRules {
+        explode_depth: 4,
+        split_threshold: 10,
+        split_rounding: Rounding::Down,
+        magnitude_weights: (3, 2),
+    };
+
+    // validate checks the rules that reducing relies on, which part1_with and part2_with do before
+    // using them.
+    pub fn validate(&self) -> Result<()> {
+        if self.explode_depth < 1 {
+            return Err(AocError::invalid("explode depth must be at least 1"));
+        }
+        if self.split_threshold < 2 {
+            return Err(AocError::invalid("split threshold must be at least 2, or splitting never ends"));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn explodes(&self, depth: usize) -> bool {
+        depth >= self.explode_depth
+    }
+
+    pub(crate) fn splits(&self, n: i32) -> bool {
+        n >= self.split_threshold
+    }
+
+    pub(crate) fn split(&self, n: i32) -> (i32, i32) {
+        let left = match self.split_rounding {
+            Rounding::Down => n / 2,
+            Rounding::Up => n - n / 2,
+        };
+        (left, n - left)
+    }
+
+    pub(crate) fn magnitude(&self, left: i32, right: i32) -> Option<i32> {
+        self.magnitude_weights.0.checked_mul(left)?.checked_add(self.magnitude_weights.1.checked_mul(right)?)
+    }
+}
+
+impl Default for SnailfishRules {
+    fn default() -> Self {
+        SnailfishRules::PUZZLE
+    }
+}
@@ -0,0 +1,501 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::io::BufReader;
+
+use super::*;
+use crate::trace::{Action, Side, Step};
+
+fn number(s: &str) -> Number {
+    s.parse().unwrap()
+}
+
+const RULES: &SnailfishRules = &SnailfishRules::PUZZLE;
+
+const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+#[test]
+fn test_part1() {
+    assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "4140");
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "3993");
+}
+
+#[test]
+fn test_add_01() {
+    assert_eq!(
+        (number("[1,2]") + number("[[3,4],5]")).to_string(),
+        "[[1,2],[[3,4],5]]");
+}
+
+#[test]
+fn test_explode_1() {
+    let mut n = number("[[[[[9,8],1],2],3],4]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[[[[0,9],2],3],4]");
+}
+
+#[test]
+fn test_explode_2() {
+    let mut n = number("[7,[6,[5,[4,[3,2]]]]]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[7,[6,[5,[7,0]]]]");
+}
+
+#[test]
+fn test_explode_3() {
+    let mut n = number("[[6,[5,[4,[3,2]]]],1]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[[6,[5,[7,0]]],3]");
+}
+
+#[test]
+fn test_explode_4() {
+    let mut n = number("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]");
+}
+
+#[test]
+fn test_explode_5() {
+    let mut n = number("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[[3,[2,[8,0]]],[9,[5,[7,0]]]]");
+}
+
+#[test]
+fn test_split_1() {
+    let mut n = Number::Single(10);
+    assert_eq!(n.reduce_split_once(RULES), true);
+    assert_eq!(n.to_string(), "[5,5]");
+}
+
+#[test]
+fn test_split_2() {
+    let mut n = Number::Single(11);
+    assert_eq!(n.reduce_split_once(RULES), true);
+    assert_eq!(n.to_string(), "[5,6]");
+}
+
+#[test]
+fn test_split_3() {
+    let mut n = Number::Single(12);
+    assert_eq!(n.reduce_split_once(RULES), true);
+    assert_eq!(n.to_string(), "[6,6]");
+}
+
+#[test]
+fn test_explode_6() {
+    let mut n = number("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]");
+}
+
+#[test]
+fn test_explode_7() {
+    let mut n = number("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[[[[0,7],4],[15,[0,13]]],[1,1]]");
+}
+
+#[test]
+fn test_split_4() {
+    let mut n = number("[[[[0,7],4],[15,[0,13]]],[1,1]]");
+    assert_eq!(n.reduce_split_once(RULES), true);
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+}
+
+#[test]
+fn test_split_5() {
+    let mut n = number("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+    assert_eq!(n.reduce_split_once(RULES), true);
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+}
+
+#[test]
+fn test_explode_8() {
+    let mut n = number("[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+    assert_eq!(n.reduce_explode_once(RULES), true);
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+}
+
+#[test]
+fn test_reduce() {
+    let mut n = number("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+    n.reduce(RULES);
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+}
+
+#[test]
+fn test_add_02() {
+    assert_eq!(
+        (number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]")).to_string(),
+        "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+}
+
+#[test]
+fn test_sum_1() {
+    let sum: Number = "[1,1]
+[2,2]
+[3,3]
+[4,4]
+".lines().map(number).sum();
+    assert_eq!(sum.to_string(), "[[[[1,1],[2,2]],[3,3]],[4,4]]");
+}
+
+#[test]
+fn test_sum_2() {
+    let sum: Number = "[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]
+".lines().map(number).sum();
+    assert_eq!(sum.to_string(), "[[[[3,0],[5,3]],[4,4]],[5,5]]");
+}
+
+#[test]
+fn test_sum_3() {
+    let sum: Number = "[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]
+[6,6]
+".lines().map(number).sum();
+    assert_eq!(sum.to_string(), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+}
+
+#[test]
+fn test_sum_4() {
+    let sum: Number = "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]
+".lines().map(number).sum();
+    assert_eq!(sum.to_string(), "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]");
+}
+
+#[test]
+fn test_add_03() {
+    assert_eq!(
+        (number("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]") +
+            number("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]")).to_string(),
+        "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]");
+}
+
+#[test]
+fn test_add_04() {
+    assert_eq!(
+        (number("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]") +
+            number("[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]")).to_string(),
+        "[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]");
+}
+
+#[test]
+fn test_add_05() {
+    assert_eq!(
+        (number("[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]") +
+            number("[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]")).to_string(),
+        "[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]");
+}
+
+#[test]
+fn test_add_06() {
+    assert_eq!(
+        (number("[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]") +
+            number("[7,[5,[[3,8],[1,4]]]]")).to_string(),
+        "[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]");
+}
+
+#[test]
+fn test_add_07() {
+    assert_eq!(
+        (number("[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]") +
+            number("[[2,[2,2]],[8,[8,1]]]")).to_string(),
+        "[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]");
+}
+
+#[test]
+fn test_add_08() {
+    assert_eq!(
+        (number("[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]") +
+            number("[2,9]")).to_string(),
+        "[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]");
+}
+
+#[test]
+fn test_add_09() {
+    assert_eq!(
+        (number("[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]") +
+            number("[1,[[[9,3],9],[[9,0],[0,7]]]]")).to_string(),
+        "[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]");
+}
+
+#[test]
+fn test_add_10() {
+    assert_eq!(
+        (number("[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]") +
+            number("[[[5,[7,4]],7],1]")).to_string(),
+        "[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]");
+}
+
+#[test]
+fn test_add_11() {
+    assert_eq!(
+        (number("[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]") +
+            number("[[[[4,2],2],6],[8,7]]")).to_string(),
+        "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]");
+}
+
+#[test]
+fn test_magnitude_1() {
+    assert_eq!(number("[[1,2],[[3,4],5]]").magnitude(RULES), Some(143));
+}
+
+#[test]
+fn test_magnitude_2() {
+    assert_eq!(number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").magnitude(RULES), Some(1384));
+}
+
+#[test]
+fn test_magnitude_3() {
+    assert_eq!(number("[[[[1,1],[2,2]],[3,3]],[4,4]]").magnitude(RULES), Some(445));
+}
+
+#[test]
+fn test_magnitude_4() {
+    assert_eq!(number("[[[[3,0],[5,3]],[4,4]],[5,5]]").magnitude(RULES), Some(791));
+}
+
+#[test]
+fn test_magnitude_5() {
+    assert_eq!(number("[[[[5,0],[7,4]],[5,5]],[6,6]]").magnitude(RULES), Some(1137));
+}
+
+#[test]
+fn test_magnitude_6() {
+    assert_eq!(
+        number("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(RULES),
+        Some(3488));
+}
+
+#[test]
+fn test_sum_5() {
+    let sum: Number = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+".lines().map(number).sum();
+    assert_eq!(sum.to_string(), "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]");
+}
+
+#[test]
+fn test_magnitude_7() {
+    assert_eq!(
+        number("[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]").magnitude(RULES),
+        Some(4140));
+}
+
+#[test]
+fn test_malformed() {
+    let err = part1(BufReader::new("[1,2]\n[[1,2],3".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 9: expected \"]\", found end of line");
+    let err = part1(BufReader::new("[1,x]".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 4: expected regular number or \"[\", found \"x]\"");
+    let err = part1(BufReader::new("[1,2]]".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 6: expected end of line, found \"]\"");
+}
+
+#[test]
+fn test_round_trip() {
+    for line in include_str!("testdata/basic.txt").lines().chain(["[7,0]", "[[15,0],[0,123]]"]) {
+        assert_eq!(number(line).to_string(), line);
+    }
+}
+
+#[test]
+fn test_from_str_errors() {
+    let error = |s: &str| s.parse::<Number>().unwrap_err().to_string();
+    assert_eq!(error(""), "line 1, column 1: expected \"[\", found end of line");
+    assert_eq!(error("5"), "line 1, column 1: expected \"[\", found \"5\"");
+    assert_eq!(error("2147483647"), "line 1, column 1: expected \"[\", found \"2147483647\"");
+    assert_eq!(error("[[1,2],3"), "line 1, column 9: expected \"]\", found end of line");
+    assert_eq!(error("[1,2]]"), "line 1, column 6: expected end of line, found \"]\"");
+    assert_eq!(error("[1,2] "), "line 1, column 6: expected end of line, found \" \"");
+    assert_eq!(error("[1,2,3]"), "line 1, column 5: expected \"]\", found \",3]\"");
+    assert_eq!(error("[1 ,2]"), "line 1, column 3: expected \",\", found \" ,2]\"");
+    assert_eq!(error("[1,02]"), "line 1, column 4: expected regular number without leading zeros, found \"02]\"");
+    assert_eq!(error("[1,-2]"), "line 1, column 4: expected regular number or \"[\", found \"-2]\"");
+    assert_eq!(error("[1,99999999999]"), "line 1, column 4: expected regular number up to 9999, found \"99999999999]\"");
+    assert_eq!(error("[2147483646,0]"), "line 1, column 2: expected regular number up to 9999, found \"2147483646,0]\"");
+    assert_eq!(error("[]"), "line 1, column 2: expected regular number or \"[\", found \"]\"");
+}
+
+#[test]
+fn test_bare_regular_number() {
+    let err = part2(BufReader::new("[1,2]\n2147483647\n".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 1: expected \"[\", found \"2147483647\"");
+}
+
+#[test]
+fn test_input_total() {
+    // Each number fits, but together they add up to more than an i32 holds.
+    let input = "[9999,9999]\n".repeat(107385);
+    assert_eq!(part1(BufReader::new(input.as_bytes())).unwrap_err().to_string(),
+               "line 107385, column 1: expected regular numbers adding up to at most 2147483647, found \"[9999,9999]\"");
+}
+
+#[test]
+fn test_flat_matches_tree() {
+    let numbers = include_str!("testdata/basic.txt").lines().map(number).collect::<Vec<_>>();
+    let flat = numbers.iter().map(FlatNumber::from).collect::<Vec<_>>();
+    for (number, f) in numbers.iter().zip(&flat) {
+        assert_eq!(&Number::from(f), number);
+        assert_eq!(f.magnitude(RULES), number.magnitude(RULES));
+    }
+    for i in 0..numbers.len() {
+        for j in 0..numbers.len() {
+            let sum = numbers[i].clone() + numbers[j].clone();
+            assert_eq!(Number::from(&(&flat[i] + &flat[j])), sum, "{} + {}", i, j);
+        }
+    }
+    assert_eq!(Number::from(&flat.into_iter().sum::<FlatNumber>()), numbers.into_iter().sum());
+}
+
+#[test]
+fn test_flat_reduce() {
+    let sum = FlatNumber::from(&number("[[[[4,3],4],4],[7,[[8,4],9]]]")) +
+        FlatNumber::from(&number("[1,1]"));
+    assert_eq!(Number::from(&sum).to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+    assert_eq!(FlatNumber::from(&Number::Single(9)).magnitude(RULES), Some(9));
+}
+
+#[test]
+fn test_trace() {
+    let (sum, steps) = number("[[[[4,3],4],4],[7,[[8,4],9]]]").add_traced(number("[1,1]"), RULES);
+    assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+    let trace = steps.iter().map(Step::to_string).collect::<String>();
+    assert_eq!(trace, "\
+after addition: [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]
+after explode:  [[[[0,7],4],[7,[[8,4],9]]],[1,1]]
+    [4,3] at LLLL: 4 dropped, 3 added to the right
+after explode:  [[[[0,7],4],[15,[0,13]]],[1,1]]
+    [8,4] at LRRL: 8 added to the left, 4 added to the right
+after split:    [[[[0,7],4],[[7,8],[0,13]]],[1,1]]
+    15 at LRL
+after split:    [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]
+    13 at LRRR
+after explode:  [[[[0,7],4],[[7,8],[6,0]]],[8,1]]
+    [6,7] at LRRR: 6 added to the left, 7 added to the right
+");
+    assert_eq!(steps[3].action, Action::Split { path: vec![Side::Left, Side::Right, Side::Left], value: 15 });
+}
+
+#[test]
+fn test_explain() {
+    let explanation = Day.explain(Part::Two, &mut BufReader::new(BASIC)).unwrap().unwrap();
+    let first = explanation.lines().next().unwrap();
+    assert_eq!(first, "after addition: [[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]],[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]]");
+    assert!(explanation.ends_with("magnitude: 3993\n"), "{}", explanation);
+
+    let explanation = Day.explain(Part::One, &mut BufReader::new(BASIC)).unwrap().unwrap();
+    assert_eq!(explanation.matches("after addition").count(), 9);
+    assert!(explanation.ends_with("magnitude: 4140\n"), "{}", explanation);
+}
+
+const VARIANTS: &[SnailfishRules] = &[
+    SnailfishRules::PUZZLE,
+    SnailfishRules { explode_depth: 3, ..SnailfishRules::PUZZLE },
+    SnailfishRules { explode_depth: 5, split_threshold: 7, ..SnailfishRules::PUZZLE },
+    SnailfishRules { split_rounding: Rounding::Up, magnitude_weights: (2, 5), ..SnailfishRules::PUZZLE },
+    SnailfishRules { explode_depth: 1, split_threshold: 2, split_rounding: Rounding::Up, magnitude_weights: (1, 1) },
+];
+
+#[test]
+fn test_rules() {
+    let mut n = Number::Single(11);
+    assert!(n.reduce_split_once(&SnailfishRules { split_rounding: Rounding::Up, ..SnailfishRules::PUZZLE }));
+    assert_eq!(n.to_string(), "[6,5]");
+
+    let mut n = Number::Single(9);
+    assert!(n.reduce_split_once(&SnailfishRules { split_threshold: 9, ..SnailfishRules::PUZZLE }));
+    assert_eq!(n.to_string(), "[4,5]");
+
+    let mut n = number("[[[[9,8],1],2],3]");
+    assert!(!n.reduce_explode_once(RULES));
+    assert!(n.reduce_explode_once(&SnailfishRules { explode_depth: 3, ..SnailfishRules::PUZZLE }));
+    assert_eq!(n.to_string(), "[[[0,9],2],3]");
+
+    let weights = SnailfishRules { magnitude_weights: (1, 1), ..SnailfishRules::PUZZLE };
+    assert_eq!(number("[[1,2],[[3,4],5]]").magnitude(&weights), Some(15));
+}
+
+#[test]
+fn test_rules_flat_matches_tree() {
+    let numbers = include_str!("testdata/basic.txt").lines().map(number).collect::<Vec<_>>();
+    for rules in VARIANTS {
+        let reduced = numbers.iter()
+            .map(|number| {
+                let mut number = number.clone();
+                number.reduce(rules);
+                number
+            })
+            .collect::<Vec<_>>();
+        for i in 0..reduced.len() {
+            for j in 0..reduced.len() {
+                let sum = reduced[i].clone().add_with(reduced[j].clone(), rules);
+                let flat = FlatNumber::from(&reduced[i]).add_with(&FlatNumber::from(&reduced[j]), rules);
+                assert_eq!(Number::from(&flat), sum, "{:?}: {} + {}", rules, i, j);
+                assert_eq!(flat.magnitude(rules), sum.magnitude(rules));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_part_with() {
+    assert_eq!(part1_with(BufReader::new(BASIC), RULES).unwrap(), "4140");
+    assert_eq!(part2_with(BufReader::new(BASIC), RULES).unwrap(), "3993");
+
+    let rules = &VARIANTS[3];
+    let numbers = include_str!("testdata/basic.txt").lines().map(number).collect::<Vec<_>>();
+    let expected = (0..numbers.len())
+        .flat_map(|i| (0..numbers.len()).filter(move |&j| j != i).map(move |j| (i, j)))
+        .map(|(i, j)| numbers[i].clone().add_with(numbers[j].clone(), rules).magnitude(rules).unwrap())
+        .max()
+        .unwrap();
+    assert_eq!(part2_with(BufReader::new(BASIC), rules).unwrap(), expected.to_string());
+
+    let heavy = &SnailfishRules { magnitude_weights: (1000, 1000), ..SnailfishRules::PUZZLE };
+    assert_eq!(part1_with(BufReader::new(BASIC), heavy).unwrap_err().to_string(),
+               "invalid input: magnitude does not fit in 32 bits");
+    assert_eq!(part2_with(BufReader::new(BASIC), heavy).unwrap_err().to_string(),
+               "invalid input: magnitude does not fit in 32 bits");
+    assert_eq!(part1_with(BufReader::new("[1,2]".as_bytes()), heavy).unwrap(), "3000");
+}
+
+#[test]
+fn test_invalid_rules() {
+    let endless = &SnailfishRules { split_threshold: 1, ..SnailfishRules::PUZZLE };
+    assert_eq!(part1_with(BufReader::new(BASIC), endless).unwrap_err().to_string(),
+               "invalid input: split threshold must be at least 2, or splitting never ends");
+    let shallow = &SnailfishRules { explode_depth: 0, ..SnailfishRules::PUZZLE };
+    assert_eq!(part2_with(BufReader::new(BASIC), shallow).unwrap_err().to_string(),
+               "invalid input: explode depth must be at least 1");
+    assert!(VARIANTS.iter().all(|rules| rules.validate().is_ok()));
+}
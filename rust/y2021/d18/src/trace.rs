@@ -0,0 +1,143 @@
+use std::fmt::{Display, Formatter};
+
+use aoc_core::Result;
+
+use crate::{magnitude_overflow, Number};
+use crate::rules::SnailfishRules;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) enum Side {
+    Left,
+    Right,
+}
+
+// Action is one thing that happens while adding two numbers. Paths lead from the outermost pair
+// down to the pair or regular number that the action applies to.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Action {
+    Addition,
+    Explode { path: Vec<Side>, pair: (i32, i32) },
+    Split { path: Vec<Side>, value: i32 },
+}
+
+// Step is an action along with the number it left behind, rendered the way the puzzle does.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Step {
+    pub(crate) action: Action,
+    pub(crate) number: String,
+}
+
+impl Number {
+    // add_traced adds two numbers like add_with does, recording every step along the way.
+    pub(crate) fn add_traced(self, rhs: Number, rules: &SnailfishRules) -> (Number, Vec<Step>) {
+        let mut number = Number::Pair(Box::new(self), Box::new(rhs));
+        let mut steps = vec![Step { action: Action::Addition, number: number.to_string() }];
+        loop {
+            let action = if let Some(path) = number.explosion_path(rules) {
+                let pair = number.at(&path).get_exploding_numbers();
+                number.reduce_explode_once(rules);
+                Action::Explode { path, pair }
+            } else if let Some(path) = number.split_path(rules) {
+                let Number::Single(value) = *number.at(&path) else { unreachable!() };
+                number.reduce_split_once(rules);
+                Action::Split { path, value }
+            } else {
+                return (number, steps);
+            };
+            steps.push(Step { action, number: number.to_string() });
+        }
+    }
+
+    // explosion_path finds the leftmost pair of regular numbers that is nested deeply enough to
+    // explode, which is the one that reduce_explode_once explodes.
+    fn explosion_path(&self, rules: &SnailfishRules) -> Option<Vec<Side>> {
+        self.find(&mut Vec::new(), &|number, path| match number {
+            Number::Pair(l, r) => {
+                matches!((&**l, &**r), (Number::Single(_), Number::Single(_))) && rules.explodes(path.len())
+            }
+            Number::Single(_) => false,
+        })
+    }
+
+    // split_path finds the leftmost regular number that is large enough to split, which is the
+    // one that reduce_split_once splits.
+    fn split_path(&self, rules: &SnailfishRules) -> Option<Vec<Side>> {
+        self.find(&mut Vec::new(), &|number, _| matches!(number, Number::Single(n) if rules.splits(*n)))
+    }
+
+    fn find(&self, path: &mut Vec<Side>, matches: &impl Fn(&Number, &[Side]) -> bool) -> Option<Vec<Side>> {
+        if matches(self, path) {
+            return Some(path.clone());
+        }
+        let Number::Pair(l, r) = self else { return None };
+        for (side, child) in [(Side::Left, l), (Side::Right, r)] {
+            path.push(side);
+            let found = child.find(path, matches);
+            path.pop();
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+
+    fn at(&self, path: &[Side]) -> &Number {
+        path.iter().fold(self, |number, side| match (number, side) {
+            (Number::Pair(l, _), Side::Left) => l,
+            (Number::Pair(_, r), Side::Right) => r,
+            (Number::Single(_), _) => panic!("path leads past a regular number"),
+        })
+    }
+}
+
+// explain lists the steps of adding up numbers in turn, as the puzzle's worked examples do.
+pub(crate) fn explain(numbers: Vec<Number>, rules: &SnailfishRules) -> Result<String> {
+    let mut output = String::new();
+    let mut numbers = numbers.into_iter();
+    let Some(mut sum) = numbers.next() else { return Ok(output) };
+    for number in numbers {
+        let (result, steps) = sum.add_traced(number, rules);
+        for step in steps {
+            output.push_str(&step.to_string());
+        }
+        output.push('\n');
+        sum = result;
+    }
+    let magnitude = sum.magnitude(rules).ok_or_else(magnitude_overflow)?;
+    output.push_str(&format!("magnitude: {}\n", magnitude));
+    Ok(output)
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.action {
+            Action::Addition => writeln!(f, "after addition: {}", self.number),
+            Action::Explode { path, pair: (l, r) } => {
+                writeln!(f, "after explode:  {}", self.number)?;
+                // A pair has a regular number to its left unless it is on the far left, and so on.
+                let left = if path.contains(&Side::Right) { "added to the left" } else { "dropped" };
+                let right = if path.contains(&Side::Left) { "added to the right" } else { "dropped" };
+                writeln!(f, "    [{},{}] at {}: {} {}, {} {}", l, r, Path(path), l, left, r, right)
+            }
+            Action::Split { path, value } => {
+                writeln!(f, "after split:    {}", self.number)?;
+                writeln!(f, "    {} at {}", value, Path(path))
+            }
+        }
+    }
+}
+
+// Path renders a path as the sides it takes, such as "LLRL".
+struct Path<'a>(&'a [Side]);
+
+impl Display for Path<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "the top");
+        }
+        for side in self.0 {
+            write!(f, "{}", if *side == Side::Left { "L" } else { "R" })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{AocError, Result};
+
+use crate::transform::{ALL_ORIENTATIONS, Coord, Matrix4, Vector3};
+
+// Aligner works out how point clouds, such as the beacons that each scanner sees, fit together.
+// Two clouds overlap when one of the 24 orientations plus a translation maps at least min_overlap
+// points of one onto points of the other.
+pub struct Aligner {
+    min_overlap: usize,
+    regions: Vec<Region>,
+}
+
+// Alignment is the result of aligning every registered cloud with the first one. A cloud's pose
+// maps its points into the first cloud's frame, and is None if no chain of overlaps connects it to
+// the first cloud. The merged cloud has every point of the connected clouds once. The groups are
+// the clouds that chains of overlaps connect to each other, starting with the first cloud's group.
+pub struct Alignment {
+    pub poses: Vec<Option<Matrix4>>,
+    pub merged: Vec<Vector3>,
+    pub groups: Vec<Vec<usize>>,
+}
+
+impl Alignment {
+    pub fn unconnected(&self) -> Vec<usize> {
+        (0..self.poses.len()).filter(|&i| self.poses[i].is_none()).collect()
+    }
+}
+
+impl Aligner {
+    // The puzzle's scanners overlap when they see at least 12 of the same beacons.
+    pub const PUZZLE_MIN_OVERLAP: usize = 12;
+
+    // new fails if min_overlap is below 2, since a single shared point cannot say how a cloud is
+    // oriented.
+    pub fn new(min_overlap: usize) -> Result<Self> {
+        if min_overlap < 2 {
+            return Err(AocError::invalid("clouds must overlap by at least 2 points to be oriented"));
+        }
+        Ok(Aligner { min_overlap, regions: Vec::new() })
+    }
+
+    // register adds a cloud, returning its index among the poses.
+    pub fn register(&mut self, points: impl IntoIterator<Item=Vector3>) -> usize {
+        self.regions.push(points.into_iter().collect());
+        self.regions.len() - 1
+    }
+
+    pub fn align(&self) -> Alignment {
+        let regions = &self.regions;
+        // Each group is posed in the frame of its first cloud, which is only the first cloud's
+        // frame for the first group.
+        let mut frames = vec![None; regions.len()];
+        let mut groups = Vec::new();
+
+        for root in 0..regions.len() {
+            if frames[root].is_some() {
+                continue;
+            }
+            let mut group = Vec::new();
+            let mut queue = vec![(root, Matrix4::IDENTITY)];
+
+            while let Some((base_index, base_pose)) = queue.pop() {
+                if frames[base_index].is_some() {
+                    continue;
+                }
+                frames[base_index] = Some(base_pose);
+                group.push(base_index);
+
+                for i in 0..regions.len() {
+                    if frames[i].is_some() {
+                        continue;
+                    }
+                    let transform = regions[i].get_transform_relative_to(&regions[base_index], self.min_overlap);
+                    if let Some(transform) = transform {
+                        queue.push((i, base_pose * transform));
+                    }
+                }
+            }
+
+            group.sort();
+            groups.push(group);
+        }
+
+        let mut poses = vec![None; regions.len()];
+        for &i in groups.first().into_iter().flatten() {
+            poses[i] = frames[i];
+        }
+
+        let mut merged = regions.iter()
+            .zip(&poses)
+            .filter_map(|(region, pose)| pose.map(|pose| region.beacons.iter().map(move |&b| pose * b)))
+            .flatten()
+            .collect::<HashSet<Vector3>>()
+            .into_iter()
+            .collect::<Vec<Vector3>>();
+        merged.sort();
+
+        Alignment { poses, merged, groups }
+    }
+}
+
+#[derive(Default)]
+struct Region {
+    // The beacon positions:
+    beacons: Vec<Vector3>,
+    // Markers for finding overlapping regions (how many combinations of 2 beacons are each distance apart):
+    distance_markers: HashMap<Coord, usize>,
+    // Markers for orienting regions (vectors between every combination of 2 beacons, along with the beacons
+    // they start from, since different combinations can have the same vector):
+    orientation_markers: HashMap<Vector3, Vec<Vector3>>,
+}
+
+impl Region {
+    fn insert(&mut self, beacon: Vector3) {
+        for &cur_beacon in &self.beacons {
+            let orientation_marker = cur_beacon - beacon;
+            self.orientation_markers.entry(orientation_marker).or_default().push(cur_beacon);
+            self.orientation_markers.entry(-orientation_marker).or_default().push(beacon);
+            *self.distance_markers.entry(orientation_marker.manhattan_distance()).or_default() += 1;
+        }
+        self.beacons.push(beacon);
+    }
+
+    fn get_transform_relative_to(&self, other: &Self, min_overlap: usize) -> Option<Matrix4> {
+        // Every combination of 2 of the overlapping beacons is the same distance apart in both regions.
+        let required_markers = min_overlap * (min_overlap - 1) / 2;
+        let shared_markers = self.distance_markers.iter()
+            .map(|(distance, &count)| count.min(other.distance_markers.get(distance).copied().unwrap_or(0)))
+            .sum::<usize>();
+        if shared_markers < required_markers {
+            return None;
+        }
+
+        for &try_rotation in ALL_ORIENTATIONS.iter() {
+            // Each vector between 2 beacons that both regions share votes for the translations that
+            // would line up the beacons it starts from. The overlapping beacons give the right
+            // translation a vote in each direction, but vectors can repeat, so every translation
+            // with enough votes is checked in turn.
+            let mut votes: HashMap<Vector3, usize> = HashMap::new();
+            for (&marker, starts) in &self.orientation_markers {
+                let Some(other_starts) = other.orientation_markers.get(&(try_rotation * marker)) else { continue };
+                for &start in starts {
+                    for &other_start in other_starts {
+                        *votes.entry(other_start - try_rotation * start).or_default() += 1;
+                    }
+                }
+            }
+
+            let mut translations = votes.into_iter()
+                .filter(|&(_, count)| count >= 2 * required_markers)
+                .collect::<Vec<(Vector3, usize)>>();
+            translations.sort_by_key(|&(translation, count)| (Reverse(count), translation));
+
+            for (translation, _) in translations {
+                let transform = try_rotation + translation;
+                if self.overlap(other, transform) >= min_overlap {
+                    return Some(transform);
+                }
+            }
+        }
+
+        None
+    }
+
+    // overlap counts the beacons that transform maps onto beacons of the other region.
+    fn overlap(&self, other: &Self, transform: Matrix4) -> usize {
+        self.beacons.iter().filter(|&&beacon| other.beacons.contains(&(transform * beacon))).count()
+    }
+}
+
+impl FromIterator<Vector3> for Region {
+    fn from_iter<T: IntoIterator<Item=Vector3>>(iter: T) -> Self {
+        let mut region = Region::default();
+        iter.into_iter().for_each(|v| region.insert(v));
+        region
+    }
+}
@@ -0,0 +1,106 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, lines, Result, Solution};
+use aoc_parse::blocks;
+
+pub use align::{Aligner, Alignment};
+pub use transform::{Coord, Matrix4, Vector3};
+
+mod align;
+mod transform;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Beacon Scanner"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("79"), Some("3621")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let alignment = align(read_regions(reader)?)?;
+    Ok(alignment.merged.len().to_string())
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let alignment = align(read_regions(reader)?)?;
+    let poses = alignment.poses.into_iter().flatten().collect::<Vec<Matrix4>>();
+    Ok(get_largest_manhattan_distance_between_scanners(poses).to_string())
+}
+
+// align aligns every scanner's beacons with scanner 0's, which the puzzle needs all of.
+fn align(regions: Vec<Vec<Vector3>>) -> Result<Alignment> {
+    let mut aligner = Aligner::new(Aligner::PUZZLE_MIN_OVERLAP)?;
+    for beacons in regions {
+        aligner.register(beacons);
+    }
+    let alignment = aligner.align();
+
+    if alignment.groups.len() > 1 {
+        let groups = alignment.groups.iter().map(|group| format!("{:?}", group)).collect::<Vec<String>>();
+        return Err(AocError::no_solution(format!("scanner groups {} do not overlap each other", groups.join(", "))));
+    }
+
+    Ok(alignment)
+}
+
+fn get_largest_manhattan_distance_between_scanners(region_transforms: Vec<Matrix4>) -> Coord {
+    let translations = region_transforms.into_iter()
+        .map(Matrix4::translation)
+        .collect::<Vec<Vector3>>();
+
+    translations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)|
+            translations[i + 1..]
+                .iter()
+                .map(move |&b| b - a)
+                .map(Vector3::manhattan_distance)
+        )
+        .max()
+        .unwrap_or(0)
+}
+
+fn read_regions<R: BufRead>(reader: R) -> Result<Vec<Vec<Vector3>>> {
+    let mut regions = Vec::new();
+    for block in blocks(lines(reader)) {
+        let block = block?;
+        // Blocks are never empty, so there is always a header.
+        let (header, beacons) = block.split_first().unwrap();
+        header.strip_prefix(&header.text, "--- scanner ")?;
+        regions.push(beacons.iter().map(Vector3::parse).collect::<Result<Vec<Vector3>>>()?);
+    }
+    if regions.is_empty() {
+        return Err(AocError::end_of_input(1, "scanner header"));
+    }
+    Ok(regions)
+}
+
+#[cfg(test)]
+mod tests;
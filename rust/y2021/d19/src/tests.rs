@@ -0,0 +1,141 @@
+use std::io::BufReader;
+
+use super::*;
+
+const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+#[test]
+fn test_part1() {
+    assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "79")
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "3621")
+}
+
+#[test]
+fn test_malformed() {
+    let err = part1(BufReader::new("--- scanner 0 ---\n404,-588,-901\n528,-643\n".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "line 3, column 5: expected \",\", found \"-643\"");
+    let err = part1(BufReader::new("404,-588,-901\n".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 1: expected \"--- scanner \", found \"404,-588,-901\"");
+}
+
+#[test]
+fn test_disconnected() {
+    let err = part1(BufReader::new("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6\n".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "no solution: scanner groups [0], [1] do not overlap each other");
+
+    // Scanners 1 and 3 see the same beacons as each other, but none that scanners 0 or 2 see.
+    let mut input = String::from_utf8(BASIC.to_vec()).unwrap();
+    input = input.replacen("--- scanner 0 ---", "--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---", 1);
+    let scanners = input.split("\n\n").collect::<Vec<&str>>();
+    let input = [scanners[0], scanners[1], scanners[3], scanners[1]].join("\n\n");
+    let err = part1(BufReader::new(input.as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "no solution: scanner groups [0], [1, 3], [2] do not overlap each other");
+}
+
+fn vector(x: Coord, y: Coord, z: Coord) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+#[test]
+fn test_aligner() {
+    let mut aligner = Aligner::new(Aligner::PUZZLE_MIN_OVERLAP).unwrap();
+    for region in read_regions(BufReader::new(BASIC)).unwrap() {
+        aligner.register(region);
+    }
+    let alignment = aligner.align();
+    assert_eq!(alignment.merged.len(), 79);
+    assert!(alignment.unconnected().is_empty());
+    let scanners = alignment.poses.iter().map(|pose| pose.unwrap().translation()).collect::<Vec<_>>();
+    assert_eq!(scanners, [
+        vector(0, 0, 0),
+        vector(68, -1246, -43),
+        vector(1105, -1205, 1229),
+        vector(-92, -2380, -20),
+        vector(-20, -1133, 1061),
+    ]);
+    assert_eq!(alignment.poses[0], Some(Matrix4::IDENTITY));
+}
+
+#[test]
+fn test_aligner_min_overlap() {
+    let points = [vector(0, 0, 0), vector(1, 2, 4), vector(10, 3, 7), vector(5, 20, 1)];
+    // The second cloud sees the same points rotated 90 degrees about z from 100,0,0, along with
+    // a point that the first cloud does not.
+    let seen = points.iter().map(|p| vector(p.y - 100, -p.x, p.z)).chain([vector(50, 50, 50)]);
+
+    let mut aligner = Aligner::new(4).unwrap();
+    aligner.register(points);
+    aligner.register(seen.clone());
+    let alignment = aligner.align();
+    assert!(alignment.unconnected().is_empty());
+    assert_eq!(alignment.merged.len(), 5);
+    let pose = alignment.poses[1].unwrap();
+    assert_eq!(pose * vector(-100, 0, 0), vector(0, 0, 0));
+    assert!(alignment.merged.contains(&(pose * vector(50, 50, 50))));
+
+    let mut aligner = Aligner::new(5).unwrap();
+    aligner.register(points);
+    aligner.register(seen);
+    let alignment = aligner.align();
+    assert_eq!(alignment.unconnected(), [1]);
+    assert_eq!(alignment.merged.len(), 4);
+}
+
+#[test]
+fn test_aligner_too_small_overlap() {
+    for min_overlap in [0, 1] {
+        let err = Aligner::new(min_overlap).err().unwrap();
+        assert_eq!(err.to_string(), "invalid input: clouds must overlap by at least 2 points to be oriented");
+    }
+    assert!(Aligner::new(2).is_ok());
+}
+
+#[test]
+fn test_aligner_groups() {
+    let points = [vector(0, 0, 0), vector(1, 2, 4), vector(10, 3, 7), vector(5, 20, 1)];
+    let shifted = points.map(|p| vector(p.x + 7, p.y, p.z));
+
+    let mut aligner = Aligner::new(4).unwrap();
+    aligner.register([vector(0, 0, 0), vector(50, 50, 50)]);
+    aligner.register(points);
+    aligner.register([vector(-1, -1, -1)]);
+    aligner.register(shifted);
+    let alignment = aligner.align();
+    assert_eq!(alignment.groups, [vec![0], vec![1, 3], vec![2]]);
+    assert_eq!(alignment.poses, [Some(Matrix4::IDENTITY), None, None, None]);
+    assert_eq!(alignment.merged, [vector(0, 0, 0), vector(50, 50, 50)]);
+}
+
+#[test]
+fn test_aligner_repeated_markers() {
+    // The corners of a box are the same distances and vectors apart many times over, so the first
+    // translation that some pair of corners suggests is often wrong.
+    let corners = (0..8).map(|i| vector(i & 1, 2 * (i >> 1 & 1), 4 * (i >> 2))).collect::<Vec<Vector3>>();
+    let seen = corners.iter().map(|p| vector(-p.z + 30, p.y - 20, p.x + 10)).chain([vector(9, 9, 9)]);
+
+    let mut aligner = Aligner::new(8).unwrap();
+    aligner.register(corners.clone());
+    aligner.register(seen);
+    let alignment = aligner.align();
+    assert!(alignment.unconnected().is_empty());
+    let pose = alignment.poses[1].unwrap();
+    for &corner in &corners {
+        assert_eq!(pose * vector(-corner.z + 30, corner.y - 20, corner.x + 10), corner);
+    }
+    assert_eq!(alignment.merged.len(), 9);
+
+    // Moving one corner leaves 7 corners that line up, which connects the clouds when 7 must
+    // overlap but not when all 8 must.
+    let mut moved = corners.clone();
+    moved[7].x += 1;
+    for (min_overlap, unconnected) in [(8, vec![1]), (7, vec![])] {
+        let mut aligner = Aligner::new(min_overlap).unwrap();
+        aligner.register(corners.clone());
+        aligner.register(moved.clone());
+        assert_eq!(aligner.align().unconnected(), unconnected);
+    }
+}
@@ -1,8 +1,11 @@
 use std::ops::{Add, Index, Mul, MulAssign, Neg, Sub};
 
+use aoc_core::{Line, Result};
+use aoc_parse::{integer, parse_line, triple};
+
 pub type Coord = i32;
 
-#[derive(Default, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
+#[derive(Debug, Default, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
 pub struct Vector3 {
     pub x: Coord,
     pub y: Coord,
@@ -15,14 +18,11 @@ impl Vector3 {
     }
 }
 
-impl From<String> for Vector3 {
-    fn from(s: String) -> Self {
-        let mut output = Vector3::default();
-        let mut split = s.split(",");
-        output.x = split.next().unwrap().parse().unwrap();
-        output.y = split.next().unwrap().parse().unwrap();
-        output.z = split.next().unwrap().parse().unwrap();
-        output
+impl Vector3 {
+    pub fn parse(line: &Line) -> Result<Self> {
+        let coordinates = triple(integer("x coordinate"), ",", integer("y coordinate"), integer("z coordinate"));
+        let (x, y, z) = parse_line(line, coordinates)?;
+        Ok(Vector3 { x, y, z })
     }
 }
 
@@ -50,7 +50,7 @@ impl Neg for Vector3 {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Matrix4([[Coord; 4]; 4]);
 
 impl Mul for Matrix4 {
@@ -0,0 +1,172 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Line, lines, Result, Solution, SparseGrid};
+use aoc_parse::char_grid;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Trench Map"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("35"), Some("3351")),
+            Example::new("infinity", include_str!("testdata/infinity.txt"), Some("7"), None),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, 2)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, 50)
+}
+
+fn solve<R: BufRead>(reader: R, n: usize) -> Result<String> {
+    let (mut image, algorithm) = parse_input(reader)?;
+
+    for _ in 0..n {
+        image = image.enhance(&algorithm);
+    }
+
+    Ok(image.count_pixels()?.to_string())
+}
+
+// Image is an infinite image. Beyond the part that has been enhanced so far, every pixel is the
+// same: the background, which enhancing can flip.
+struct Image(SparseGrid<bool>);
+
+impl Image {
+    fn enhance(&self, algorithm: &[bool]) -> Self {
+        let background = if *self.0.background() { algorithm[0b111111111] } else { algorithm[0] };
+        let mut output = SparseGrid::new(background);
+
+        // Only the pixels within a pixel of those that differ from the background can differ from
+        // the new background.
+        if let Some(bounds) = self.0.bounds() {
+            for (i, j) in bounds.grow(1).positions() {
+                let mut lookup_key = 0usize;
+
+                for i in i - 1..=i + 1 {
+                    for j in j - 1..=j + 1 {
+                        lookup_key <<= 1;
+                        if *self.0.get((i, j)) {
+                            lookup_key |= 1;
+                        }
+                    }
+                }
+
+                output.set((i, j), algorithm[lookup_key]);
+            }
+        }
+
+        Image(output)
+    }
+
+    fn count_pixels(&self) -> Result<usize> {
+        if *self.0.background() {
+            return Err(AocError::no_solution("an infinite number of pixels are lit"));
+        }
+        Ok(self.0.len())
+    }
+}
+
+const ALGORITHM_SIZE: usize = 512;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Image, Vec<bool>)> {
+    let mut lines = lines(reader);
+
+    let line = lines.expect("image enhancement algorithm")?;
+    let algorithm = parse_pixels(&line)?;
+    if algorithm.len() != ALGORITHM_SIZE {
+        return Err(line.error(&line.text, format!("{} pixel algorithm", ALGORITHM_SIZE)));
+    }
+
+    let line = lines.expect("blank line")?;
+    if !line.is_empty() {
+        return Err(line.error(&line.text, "blank line"));
+    }
+
+    let pixels = char_grid(lines, "\"#\" or \".\"", pixel)?;
+    let mut image = Image(SparseGrid::new(false));
+    for (i, j) in pixels.positions() {
+        image.0.set((i as i64, j as i64), pixels[(i, j)]);
+    }
+
+    Ok((image, algorithm))
+}
+
+fn parse_pixels(line: &Line) -> Result<Vec<bool>> {
+    line.text.char_indices().map(|(i, c)| {
+        pixel(c).ok_or_else(|| line.error(&line.text[i..i + c.len_utf8()], "\"#\" or \".\""))
+    }).collect()
+}
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+    const INFINITY: &[u8] = include_str!("testdata/infinity.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "35")
+    }
+
+    #[test]
+    fn test_part1_infinity() {
+        assert_eq!(part1(BufReader::new(INFINITY)).unwrap(), "7")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "3351")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("#.#\n\n#.".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 1: expected 512 pixel algorithm, found \"#.#\"");
+        let input = format!("{}\n\n#.\n.x", ".".repeat(512));
+        let err = part1(BufReader::new(input.as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 2: expected \"#\" or \".\", found \"x\"");
+    }
+
+    #[test]
+    fn test_infinite_pixels() {
+        let input = format!("#{}#\n\n#.", ".".repeat(510));
+        let err = part1(BufReader::new(input.as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "no solution: an infinite number of pixels are lit");
+    }
+}
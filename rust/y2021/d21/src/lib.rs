@@ -1,18 +1,42 @@
-#![feature(test)]
-
 use std::cmp::{max, min};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use std::io::BufRead;
 use std::ops::{Add, Mul};
 
-fn main() {
-    println!("{}", part1(read_input()));
-    println!("{}", part2(read_input()));
+use aoc_core::{Example, Lines, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        21
+    }
+
+    fn title(&self) -> &'static str {
+        "Dirac Dice"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("739785"), Some("444356092776315")),
+        ]
+    }
 }
 
-fn part1<R: BufRead>(reader: R) -> String {
-    let (p1_pos, p2_pos) = parse_input(reader);
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let (p1_pos, p2_pos) = parse_input(reader)?;
     let mut p1_pos = p1_pos as u32;
     let mut p2_pos = p2_pos as u32;
     let mut p1_score = 0;
@@ -40,29 +64,34 @@ fn part1<R: BufRead>(reader: R) -> String {
         is_p1_turn = !is_p1_turn;
     }
 
-    (min(p1_score, p2_score) * num_rolls).to_string()
+    Ok((min(p1_score, p2_score) * num_rolls).to_string())
 }
 
-fn part2<R: BufRead>(reader: R) -> String {
-    let (p1_pos, p2_pos) = parse_input(reader);
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let (p1_pos, p2_pos) = parse_input(reader)?;
     let args = State::new(p1_pos, p2_pos);
     let mut memo = Memo::default();
     let wins = count_multiverse_winners(args, &mut memo);
-    max(wins.p1_wins, wins.p2_wins).to_string()
+    Ok(max(wins.p1_wins, wins.p2_wins).to_string())
 }
 
-fn parse_input<R: BufRead>(reader: R) -> (u8, u8) {
-    let lines = &mut reader.lines();
+fn parse_input<R: BufRead>(reader: R) -> Result<(u8, u8)> {
+    let lines = &mut lines(reader);
 
-    let p1_pos = read_pos(lines);
-    let p2_pos = read_pos(lines);
+    let p1_pos = read_pos(lines, 1)?;
+    let p2_pos = read_pos(lines, 2)?;
 
-    (p1_pos, p2_pos)
+    Ok((p1_pos, p2_pos))
 }
 
-fn read_pos<R: BufRead>(lines: &mut Lines<R>) -> u8 {
-    // Position is converted to zero-indexed.
-    lines.next().unwrap().unwrap().split(": ").nth(1).unwrap().parse::<u8>().unwrap() - 1
+fn read_pos<R: BufRead>(lines: &mut Lines<R>, player: u8) -> Result<u8> {
+    let line = lines.expect("starting position")?;
+    let rest = line.strip_prefix(&line.text, &format!("Player {} starting position: ", player))?;
+    match line.parse::<u8>(rest, "position between 1 and 10")? {
+        // Position is converted to zero-indexed.
+        pos @ 1..=10 => Ok(pos - 1),
+        _ => Err(line.error(rest, "position between 1 and 10")),
+    }
 }
 
 fn count_multiverse_winners(state: State, memo: &mut Memo) -> Wins {
@@ -118,7 +147,7 @@ struct Memo(HashMap<State, Wins>);
 
 impl Memo {
     fn get(&self, state: &State) -> Option<Wins> {
-        self.0.get(state).map(|w| *w).or_else(|| {
+        self.0.get(state).copied().or_else(|| {
             self.0.get(&State {
                 p1_score: state.p2_score,
                 p2_score: state.p1_score,
@@ -175,16 +204,9 @@ impl Add for Wins {
     }
 }
 
-fn read_input() -> BufReader<File> {
-    BufReader::new(File::open("input.txt").unwrap())
-}
-
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
-    use std::fs;
-    use test::Bencher;
+    use std::io::BufReader;
 
     use super::*;
 
@@ -192,25 +214,19 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(BufReader::new(BASIC)), "739785")
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "739785")
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(BufReader::new(BASIC)), "444356092776315")
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "444356092776315")
     }
 
-    #[bench]
-    fn bench_part1(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        let input = input.as_bytes();
-        b.iter(|| part1(BufReader::new(input)))
-    }
-
-    #[bench]
-    fn bench_part2(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        let input = input.as_bytes();
-        b.iter(|| part2(BufReader::new(input)))
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("Player 1 starting position: 4\nPlayer 2 starting position: 11".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 29: expected position between 1 and 10, found \"11\"");
+        let err = part1(BufReader::new("Player 1 starting position: 4".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected starting position, found end of input");
     }
 }
@@ -0,0 +1,235 @@
+use std::cmp::{max, min};
+use std::io::BufRead;
+use std::iter::Sum;
+use std::ops::Sub;
+
+use aoc_core::{Example, Input, IntoInput, Line, Result, Solution};
+use aoc_parse::{one_of, pair, parse_line, prefixed, range, triple};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        22
+    }
+
+    fn title(&self) -> &'static str {
+        "Reactor Reboot"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("small", include_str!("testdata/small.txt"), Some("39"), None),
+            Example::new("medium", include_str!("testdata/medium.txt"), Some("590784"), None),
+            Example::new("large", include_str!("testdata/large.txt"), Some("474140"), Some("2758514936282235")),
+        ]
+    }
+}
+
+pub fn part1<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let initialization_volume = Volume(
+        Range(-50, 51),
+        Range(-50, 51),
+        Range(-50, 51),
+    );
+
+    let steps = parse_input(&input.into_input()?)?.into_iter()
+        .filter(|step| initialization_volume.contains(&step.volume))
+        .collect();
+
+    Ok(count_cubes_after_steps(steps).to_string())
+}
+
+pub fn part2<'a>(input: impl IntoInput<'a>) -> Result<String> {
+    let steps = parse_input(&input.into_input()?)?;
+    Ok(count_cubes_after_steps(steps).to_string())
+}
+
+fn count_cubes_after_steps(steps: Vec<Step>) -> i128 {
+    let mut processed_steps = Vec::new();
+
+    for step in steps {
+        processed_steps.append(&mut processed_steps.iter()
+            .filter_map(|processed| *processed - step)
+            .collect());
+
+        if step.on {
+            processed_steps.push(step);
+        }
+    }
+
+    // The running total can briefly exceed the volume of any one cuboid, so it is kept wider.
+    processed_steps.iter().sum()
+}
+
+fn parse_input(input: &Input) -> Result<Vec<Step>> {
+    let cuboid = triple(prefixed("x=", half_open), ",", prefixed("y=", half_open), prefixed("z=", half_open));
+    let step = pair(one_of(&[("on", true), ("off", false)], "on or off"), " ", cuboid);
+    input.lines()
+        .map(|line| {
+            let (on, (x, y, z)) = parse_line(&line, &step)?;
+            Ok(Step { on, volume: Volume(x, y, z) })
+        })
+        .collect()
+}
+
+const MAX_COORDINATE: i32 = 1_000_000;
+
+// Ranges are inclusive in the input, and half-open here, which makes their lengths and
+// differences simpler. Coordinates are bounded so that the volume of any cuboid fits in an i64.
+fn half_open(line: &Line, s: &str) -> Result<Range> {
+    let range = range::<i32>(line, s)?;
+    if *range.start() < -MAX_COORDINATE || *range.end() > MAX_COORDINATE {
+        return Err(line.error(s, format!("range within -{}..{}", MAX_COORDINATE, MAX_COORDINATE)));
+    }
+    Ok(Range(*range.start(), range.end() + 1))
+}
+
+#[derive(Copy, Clone)]
+struct Step {
+    volume: Volume,
+    on: bool,
+}
+
+impl Sub for Step {
+    type Output = Option<Step>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let volume = self.volume - rhs.volume;
+        if volume.size() == 0 {
+            None
+        } else {
+            Some(Step {
+                volume,
+                on: if self.on == rhs.on { !rhs.on } else { rhs.on },
+            })
+        }
+    }
+}
+
+impl<'a> Sum<&'a Step> for i128 {
+    fn sum<I: Iterator<Item=&'a Step>>(iter: I) -> Self {
+        iter.fold(0, |acc, step| {
+            if step.on {
+                acc + step.volume.size() as i128
+            } else {
+                acc - step.volume.size() as i128
+            }
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Volume(Range, Range, Range);
+
+impl Volume {
+    fn contains(&self, other: &Self) -> bool {
+        self.0.contains(&other.0) && self.1.contains(&other.1) && self.2.contains(&other.2)
+    }
+
+    fn size(&self) -> i64 {
+        self.0.len() * self.1.len() * self.2.len()
+    }
+}
+
+impl Sub for Volume {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Volume(
+            self.0 - rhs.0,
+            self.1 - rhs.1,
+            self.2 - rhs.2,
+        )
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Range(i32, i32);
+
+impl Sub for Range {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Range(min(self.1, max(self.0, rhs.0)), max(self.0, min(self.1, rhs.1)))
+    }
+}
+
+impl Range {
+    fn contains(&self, other: &Self) -> bool {
+        other.0 >= self.0 && other.1 <= self.1
+    }
+
+    fn len(&self) -> i64 {
+        (self.1 - self.0) as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const SMALL: &[u8] = include_str!("testdata/small.txt").as_bytes();
+    const MEDIUM: &[u8] = include_str!("testdata/medium.txt").as_bytes();
+    const LARGE: &[u8] = include_str!("testdata/large.txt").as_bytes();
+
+    #[test]
+    fn test_part1_small() {
+        assert_eq!(part1(BufReader::new(SMALL)).unwrap(), "39")
+    }
+
+    #[test]
+    fn test_part1_medium() {
+        assert_eq!(part1(BufReader::new(MEDIUM)).unwrap(), "590784")
+    }
+
+    #[test]
+    fn test_part1_large() {
+        assert_eq!(part1(BufReader::new(LARGE)).unwrap(), "474140")
+    }
+
+    #[test]
+    fn test_part2_large() {
+        assert_eq!(part2(BufReader::new(LARGE)).unwrap(), "2758514936282235")
+    }
+
+    #[test]
+    fn test_in_memory() {
+        assert_eq!(part1(MEDIUM).unwrap(), "590784");
+        assert_eq!(part2(LARGE.into_input().unwrap()).unwrap(), "2758514936282235");
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("on x=10..12,y=10..12,z=10..12\nof x=9..11,y=9..11,z=9..11".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected on or off, found \"of\"");
+        let err = part1(BufReader::new("on x=10..12,y=10..12".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 13: expected \",\", found \"y=10..12\"");
+        let err = part1(BufReader::new("on x=10..12,y=10..12,w=10..12".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 22: expected \"z=\", found \"w=10..12\"");
+        let err = part1(BufReader::new("on x=10..12,y=10..1x,z=10..12".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 19: expected range end, found \"1x\"");
+        let err = part2(BufReader::new("on x=0..2147483647,y=0..0,z=0..0".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 6: expected range within -1000000..1000000, found \"0..2147483647\"");
+    }
+
+    #[test]
+    fn test_largest_cuboid() {
+        let input = "on x=-1000000..1000000,y=-1000000..1000000,z=-1000000..1000000\non x=0..0,y=0..0,z=0..0";
+        assert_eq!(part2(BufReader::new(input.as_bytes())).unwrap(), (2_000_001i128.pow(3)).to_string());
+    }
+}
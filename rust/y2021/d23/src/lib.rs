@@ -1,44 +1,69 @@
-#![feature(test)]
-
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
+use aoc_core::{AocError, Example, Line, lines, Result, Solution};
 use pathfinding::directed::astar::astar;
 
-fn main() {
-    println!("{}", part1(read_input()));
-    println!("{}", part2(read_input()));
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        23
+    }
+
+    fn title(&self) -> &'static str {
+        "Amphipod"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("12521"), Some("44169")),
+        ]
+    }
 }
 
-fn part1<R: BufRead>(reader: R) -> String {
-    let start = State::from(reader);
-    let (_, cost) = astar(&start, State::successors, State::heuristic, State::success).unwrap();
-    cost.to_string()
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let start = State::parse(reader)?;
+    solve(&start)
 }
 
-fn part2<R: BufRead>(reader: R) -> String {
-    let mut start = State::from(reader);
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let mut start = State::parse(reader)?;
     start.unfold();
-    let (_, cost) = astar(&start, State::successors, State::heuristic, State::success).unwrap();
-    cost.to_string()
+    solve(&start)
+}
+
+fn solve(start: &State) -> Result<String> {
+    let (_, cost) = astar(start, State::successors, State::heuristic, State::success)
+        .ok_or_else(|| AocError::no_solution("the amphipods cannot be organized"))?;
+    Ok(cost.to_string())
 }
 
 #[derive(Copy, Clone, Hash, Eq, PartialEq)]
 enum Amphipod { A, B, C, D }
 
-impl From<char> for Amphipod {
-    fn from(c: char) -> Self {
-        match c {
-            'A' => Amphipod::A,
-            'B' => Amphipod::B,
-            'C' => Amphipod::C,
-            'D' => Amphipod::D,
-            _ => unreachable!(),
+impl Amphipod {
+    fn parse(line: &Line, s: &str) -> Result<Self> {
+        match s {
+            "A" => Ok(Amphipod::A),
+            "B" => Ok(Amphipod::B),
+            "C" => Ok(Amphipod::C),
+            "D" => Ok(Amphipod::D),
+            _ => Err(line.error(s, "amphipod A, B, C or D")),
         }
     }
-}
 
-impl Amphipod {
     fn cost(&self) -> u32 {
         match self {
             Amphipod::A => 1,
@@ -95,14 +120,14 @@ impl State {
             return vec![next];
         }
 
-        if let Some(next) = (0..State::ROOMS).into_iter()
+        if let Some(next) = (0..State::ROOMS)
             .find_map(|room| self.successor_from_room_to_room(room)) {
             return vec![next];
         }
 
         // Otherwise, explore moving amphipods into the hallway.
 
-        (0..State::ROOMS).into_iter()
+        (0..State::ROOMS)
             .filter_map(|room| self.successors_from_room_to_hall(room))
             .flatten()
             .collect()
@@ -114,14 +139,12 @@ impl State {
             Some(amphipod) => {
                 let room = amphipod.own_room();
 
-                if !self.is_room_available(room, amphipod) {
-                    None
-                } else if self.blocked_hall_to_room(hall, room) {
+                if !self.is_room_available(room, amphipod) || self.blocked_hall_to_room(hall, room) {
                     None
                 } else {
                     let slot = self.get_empty_slot(room);
 
-                    let mut next = self.clone();
+                    let mut next = *self;
                     next.rooms[room][slot] = next.hall[hall];
                     next.hall[hall] = None;
 
@@ -151,7 +174,7 @@ impl State {
             } else {
                 let to_slot = self.get_empty_slot(to_room);
 
-                let mut next = self.clone();
+                let mut next = *self;
                 next.rooms[to_room][to_slot] = next.rooms[from_room][from_slot];
                 next.rooms[from_room][from_slot] = None;
 
@@ -169,11 +192,11 @@ impl State {
         } else {
             let (slot, amphipod) = self.get_movable_amphipod_in_room(room);
 
-            Some((0..State::HALL_LEN).into_iter()
+            Some((0..State::HALL_LEN)
                 .filter_map(|hall| if self.blocked_room_to_hall(room, hall) {
                     None
                 } else {
-                    let mut next = self.clone();
+                    let mut next = *self;
                     next.hall[hall] = next.rooms[room][slot];
                     next.rooms[room][slot] = None;
 
@@ -183,12 +206,15 @@ impl State {
         }
     }
 
+    // get_empty_slot is only called for a room that holds nothing but amphipods of the type that
+    // is moving in. There are exactly as many of each type as a room has slots, and one of them is
+    // still outside, so the room always has space.
     fn get_empty_slot(&self, room: usize) -> usize {
         let (slot, _) = self.rooms[room][0..self.slot_size].iter()
             .enumerate()
             .rev()
             .find(|(_, v)| v.is_none())
-            .unwrap();
+            .expect("a room being moved into has an empty slot");
         slot
     }
 
@@ -199,11 +225,13 @@ impl State {
         })
     }
 
+    // get_movable_amphipod_in_room is only called for a room holding an amphipod that does not
+    // belong there, so the room is never empty.
     fn get_movable_amphipod_in_room(&self, room: usize) -> (usize, Amphipod) {
         self.rooms[room][0..self.slot_size].iter()
             .enumerate()
             .find_map(|(slot, value)| value.map(|amphipod| (slot, amphipod)))
-            .unwrap()
+            .expect("a room being moved out of has an amphipod")
     }
 
     fn heuristic(&self) -> u32 {
@@ -230,7 +258,7 @@ impl State {
     }
 
     fn success(&self) -> bool {
-        (0..State::ROOMS).into_iter()
+        (0..State::ROOMS)
             .all(|room| self.rooms[room][0..self.slot_size].iter()
                 .all(|s| match s {
                     None => false,
@@ -259,11 +287,7 @@ impl State {
     }
 
     fn dist_room_to_room(from_room: usize, from_slot: usize, to_room: usize, to_slot: usize) -> u32 {
-        (if from_room > to_room {
-            from_room - to_room
-        } else {
-            to_room - from_room
-        }) as u32 * 2 + 2 + from_slot as u32 + to_slot as u32
+        from_room.abs_diff(to_room) as u32 * 2 + 2 + from_slot as u32 + to_slot as u32
     }
 
     fn blocked_hall_to_room(&self, hall: usize, room: usize) -> bool {
@@ -297,36 +321,40 @@ impl State {
     }
 }
 
-impl<R: BufRead> From<R> for State {
-    fn from(reader: R) -> Self {
-        let mut lines = reader.lines();
-        lines.next();
-        lines.next();
+impl State {
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut lines = lines(reader);
+        lines.expect("wall")?;
+        lines.expect("hallway")?;
         let mut rooms = [[None; State::MAX_SLOTS]; State::ROOMS];
         for slot in 0..2 {
-            let chars = lines.next().unwrap().unwrap().chars().collect::<Vec<char>>();
-            for room in 0..State::ROOMS {
-                rooms[room][slot] = Some(Amphipod::from(chars[room * 2 + 3]));
+            let line = lines.expect("room")?;
+            for (room, slots) in rooms.iter_mut().enumerate() {
+                let i = room * 2 + 3;
+                let s = line.text.get(i..i + 1).unwrap_or(&line.text[line.text.len()..]);
+                slots[slot] = Some(Amphipod::parse(&line, s)?);
+            }
+        }
+
+        // Moving relies on there being exactly as many amphipods of each type as a room has slots.
+        for (room, name) in ["A", "B", "C", "D"].into_iter().enumerate() {
+            let count = rooms.iter().flatten().flatten().filter(|amphipod| amphipod.own_room() == room).count();
+            if count != 2 {
+                return Err(AocError::invalid(format!("found {} amphipods of type {}, expected 2", count, name)));
             }
         }
-        State {
+
+        Ok(State {
             hall: [None; State::HALL_LEN],
             rooms,
             slot_size: 2,
-        }
+        })
     }
 }
 
-fn read_input() -> BufReader<File> {
-    BufReader::new(File::open("input.txt").unwrap())
-}
-
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
-    use std::fs;
-    use test::Bencher;
+    use std::io::BufReader;
 
     use super::*;
 
@@ -334,25 +362,23 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(BufReader::new(BASIC)), "12521")
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "12521")
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(BufReader::new(BASIC)), "44169")
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "44169")
     }
 
-    #[bench]
-    fn bench_part1(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        let input = input.as_bytes();
-        b.iter(|| part1(BufReader::new(input)))
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("#############\n#...........#\n###B#C#B#D###\n  #A#D#E#A#\n".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 8: expected amphipod A, B, C or D, found \"E\"");
+        let err = part1(BufReader::new("#############\n#...........#\n###B#C#B#D###\n".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 1: expected room, found end of input");
+        let err = part2(BufReader::new("#############\n#...........#\n###A#A#A#A###\n  #A#A#A#A#\n".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "invalid input: found 8 amphipods of type A, expected 2");
+        let err = part1(BufReader::new("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#C#\n".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "invalid input: found 1 amphipods of type A, expected 2");
     }
-
-    // #[bench]
-    // fn bench_part2(b: &mut Bencher) {
-    //     let input = fs::read_to_string("input.txt").unwrap();
-    //     let input = input.as_bytes();
-    //     b.iter(|| part2(BufReader::new(input)))
-    // }
 }
@@ -0,0 +1,162 @@
+use std::io::BufRead;
+
+use aoc_core::{AocError, Example, Line, lines, Result, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        24
+    }
+
+    fn title(&self) -> &'static str {
+        "Arithmetic Logic Unit"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part2(reader)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("99598963999971"), Some("93151411711211")),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, Strategy::Largest)
+}
+
+pub fn part2<R: BufRead>(reader: R) -> Result<String> {
+    solve(reader, Strategy::Smallest)
+}
+
+fn solve<R: BufRead>(reader: R, strategy: Strategy) -> Result<String> {
+    let steps = parse_input(reader)?;
+    let mut inputs = vec![0; steps.len()];
+
+    if !solve_recurse(&steps, &mut inputs, 0, strategy) {
+        return Err(AocError::no_solution("no model number is valid"));
+    }
+
+    Ok(inputs.into_iter()
+        .fold(0u64, |acc, digit| acc * 10 + digit as u64)
+        .to_string())
+}
+
+fn solve_recurse(steps: &[Step], inputs: &mut [u8], z: i64, strategy: Strategy) -> bool {
+    if steps.is_empty() {
+        z == 0
+    } else if steps[0].trigger > 0 {
+        strategy.inputs().any(|inp| {
+            inputs[0] = inp;
+            solve_recurse(&steps[1..], &mut inputs[1..], z * 26 + inp as i64 + steps[0].inc, strategy)
+        })
+    } else {
+        let inp = z % 26 + steps[0].trigger;
+        if !(1..=9).contains(&inp) {
+            false
+        } else {
+            inputs[0] = inp as u8;
+            solve_recurse(&steps[1..], &mut inputs[1..], z / 26, strategy)
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Strategy {
+    Largest,
+    Smallest,
+}
+
+impl Strategy {
+    const INPUTS_INC: [u8; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    const INPUTS_DEC: [u8; 9] = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+
+    fn inputs(self) -> std::array::IntoIter<u8, 9> {
+        match self {
+            Strategy::Largest => Self::INPUTS_DEC.into_iter(),
+            Strategy::Smallest => Self::INPUTS_INC.into_iter(),
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct Step {
+    trigger: i64,
+    inc: i64,
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Step>> {
+    let mut lines = lines(reader);
+    let mut steps = Vec::with_capacity(14);
+    let mut cur = Step::default();
+    let mut last_line_add_y_w = false;
+    let mut last_line_div_z = false;
+
+    let first = lines.expect("inp instruction")?;
+    first.strip_prefix(&first.text, "inp ")?;
+
+    for line in lines {
+        let line = line?;
+
+        if line.text.starts_with("inp ") {
+            steps.push(cur);
+            cur = Step::default();
+        } else if last_line_div_z {
+            cur.trigger = extract_operand(&line)?;
+        } else if last_line_add_y_w {
+            cur.inc = extract_operand(&line)?;
+        }
+
+        last_line_add_y_w = line.text == "add y w";
+        last_line_div_z = line.text.starts_with("div z ");
+    }
+
+    steps.push(cur);
+
+    Ok(steps)
+}
+
+fn extract_operand(line: &Line) -> Result<i64> {
+    let mut split = line.text.split_ascii_whitespace();
+    line.next(&mut split, "instruction")?;
+    line.next(&mut split, "register")?;
+    line.parse(line.next(&mut split, "integer operand")?, "integer operand")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "99598963999971")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(BufReader::new(BASIC)).unwrap(), "93151411711211")
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("inp w\nmul x 0\ndiv z 1\nadd x y".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 7: expected integer operand, found \"y\"");
+        let err = part1(BufReader::new("mul x 0".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 1: expected \"inp \", found \"mul x 0\"");
+    }
+}
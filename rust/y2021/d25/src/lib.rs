@@ -0,0 +1,156 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+use aoc_core::{Edges, Example, Grid, lines, Part, Result, Solution};
+use aoc_parse::char_grid;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        2021
+    }
+
+    fn day(&self) -> u32 {
+        25
+    }
+
+    fn title(&self) -> &'static str {
+        "Sea Cucumber"
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead) -> Result<String> {
+        part1(reader)
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        &[Part::One]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("basic", include_str!("testdata/basic.txt"), Some("58"), None),
+        ]
+    }
+}
+
+pub fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let mut region = Region::parse(reader)?;
+    let mut step = 1;
+    while let StepResult::Moved = region.step() {
+        step += 1;
+    }
+    Ok(step.to_string())
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Cucumber {
+    Right,
+    Down,
+    None,
+}
+
+impl Cucumber {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Cucumber::None),
+            '>' => Some(Cucumber::Right),
+            'v' => Some(Cucumber::Down),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Cucumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cucumber::Right => write!(f, ">"),
+            Cucumber::Down => write!(f, "v"),
+            Cucumber::None => write!(f, "."),
+        }
+    }
+}
+
+#[derive(PartialEq)]
+enum StepResult {
+    Moved,
+    Stopped,
+}
+
+// The region wraps around: sea cucumbers that move off one edge come back on at the opposite edge.
+struct Region {
+    cucumbers: Grid<Cucumber>,
+    buffer: Grid<Cucumber>,
+}
+
+impl Region {
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let cucumbers = char_grid(lines(reader), "\".\", \">\" or \"v\"", Cucumber::parse)?
+            .with_edges(Edges::Wrapping);
+        let buffer = cucumbers.clone();
+        Ok(Region { cucumbers, buffer })
+    }
+
+    fn step(&mut self) -> StepResult {
+        let right_result = self.step_herd(Cucumber::Right, (0, 1));
+        let down_result = self.step_herd(Cucumber::Down, (1, 0));
+        if right_result == StepResult::Stopped && down_result == StepResult::Stopped {
+            StepResult::Stopped
+        } else {
+            StepResult::Moved
+        }
+    }
+
+    // step_herd moves every sea cucumber in a herd that can move, all at once.
+    fn step_herd(&mut self, herd: Cucumber, delta: (isize, isize)) -> StepResult {
+        self.buffer.clone_from(&self.cucumbers);
+
+        let mut result = StepResult::Stopped;
+
+        for pos in self.buffer.positions() {
+            let next = self.buffer.offset(pos, delta).unwrap();
+            if self.buffer[pos] == herd && self.buffer[next] == Cucumber::None {
+                self.cucumbers[pos] = Cucumber::None;
+                self.cucumbers[next] = herd;
+                result = StepResult::Moved;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    const BASIC: &[u8] = include_str!("testdata/basic.txt").as_bytes();
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(BufReader::new(BASIC)).unwrap(), "58")
+    }
+
+    #[test]
+    fn test_step() {
+        let mut region = Region::parse("...>>>>>...".as_bytes()).unwrap();
+        region.step();
+        assert_eq!(region.cucumbers.to_string(), "...>>>>.>..\n");
+        region.step();
+        assert_eq!(region.cucumbers.to_string(), "...>>>.>.>.\n");
+
+        let mut region = Region::parse("..........\n.>v....v..\n.......>..\n..........".as_bytes()).unwrap();
+        region.step();
+        assert_eq!(region.cucumbers.to_string(), "..........\n.>........\n..v....v>.\n..........\n");
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = part1(BufReader::new("v...>>\n.vv>x.".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 5: expected \".\", \">\" or \"v\", found \"x\"");
+        let err = part1(BufReader::new("v...>>\n.vv>".as_bytes())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected row of 6 columns, found \".vv>\"");
+    }
+}